@@ -39,6 +39,17 @@ use syn::{parse, Attribute, Data, DeriveInput, Fields};
 ///     C,
 /// }
 /// ```
+///
+/// # Ignoring types
+/// A whole type can be left out by using `#[typeshare(skip)]` in place of `#[typeshare]`.
+/// ```ignore
+/// use typeshare::typeshare;
+///
+/// #[typeshare(skip)]
+/// pub struct Internal {
+///     counter: u32,
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn typeshare(_attr: TokenStream, item: TokenStream) -> TokenStream {
     if let Ok(mut item) = parse::<DeriveInput>(item.clone()) {
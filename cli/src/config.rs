@@ -40,6 +40,38 @@ pub struct TypeScriptParams {
     pub type_mappings: HashMap<String, String>,
 }
 
+#[derive(Default, Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(default)]
+pub struct JsonSchemaParams {
+    pub type_mappings: HashMap<String, String>,
+}
+
+#[derive(Default, Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(default)]
+pub struct ZodParams {
+    pub type_mappings: HashMap<String, String>,
+}
+
+#[derive(Default, Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(default)]
+pub struct GraphQLParams {
+    pub type_mappings: HashMap<String, String>,
+}
+
+#[derive(Default, Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(default)]
+pub struct CSharpParams {
+    pub namespace: String,
+    pub type_mappings: HashMap<String, String>,
+}
+
+#[derive(Default, Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(default)]
+pub struct ProtobufParams {
+    pub package: String,
+    pub type_mappings: HashMap<String, String>,
+}
+
 #[derive(Default, Serialize, Deserialize, PartialEq, Eq, Debug)]
 #[serde(default)]
 #[cfg(feature = "go")]
@@ -58,6 +90,11 @@ pub(crate) struct Config {
     pub typescript: TypeScriptParams,
     pub kotlin: KotlinParams,
     pub scala: ScalaParams,
+    pub json_schema: JsonSchemaParams,
+    pub zod: ZodParams,
+    pub graphql: GraphQLParams,
+    pub csharp: CSharpParams,
+    pub protobuf: ProtobufParams,
     #[cfg(feature = "go")]
     pub go: GoParams,
 }
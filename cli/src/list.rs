@@ -0,0 +1,152 @@
+//! Lists the types that were found in the input, without generating any code.
+
+use std::io::{self, Write};
+use typeshare_core::{
+    parser::ParsedData,
+    rust_types::{Id, RustEnum, RustEnumVariant, RustField, RustType, SpecialRustType},
+};
+
+/// Writes every struct, enum and type alias in `data`, along with their fields, variants and
+/// resolved types.
+pub fn write_listing(w: &mut dyn Write, data: &ParsedData) -> io::Result<()> {
+    for s in &data.structs {
+        writeln!(w, "struct {}", name(&s.id, &s.generic_types))?;
+        for field in &s.fields {
+            write_field(w, 1, field)?;
+        }
+    }
+
+    for e in &data.enums {
+        let shared = e.shared();
+        match e {
+            RustEnum::Unit(_) => writeln!(w, "enum {}", name(&shared.id, &shared.generic_types))?,
+            RustEnum::Algebraic {
+                tag_key,
+                content_key,
+                ..
+            } => writeln!(
+                w,
+                "enum {} (tag: {:?}, content: {:?})",
+                name(&shared.id, &shared.generic_types),
+                tag_key,
+                content_key
+            )?,
+        }
+
+        for variant in &shared.variants {
+            match variant {
+                RustEnumVariant::Unit(shared) => writeln!(w, "\t{}", name(&shared.id, &[]))?,
+                RustEnumVariant::Tuple { ty, shared } => {
+                    writeln!(w, "\t{}({})", name(&shared.id, &[]), format_type(ty))?
+                }
+                RustEnumVariant::AnonymousStruct { fields, shared } => {
+                    writeln!(w, "\t{} {{", name(&shared.id, &[]))?;
+                    for field in fields {
+                        write_field(w, 2, field)?;
+                    }
+                    writeln!(w, "\t}}")?;
+                }
+            }
+        }
+    }
+
+    for a in &data.aliases {
+        writeln!(
+            w,
+            "type {} = {}",
+            name(&a.id, &a.generic_types),
+            format_type(&a.r#type)
+        )?;
+    }
+
+    Ok(())
+}
+
+fn write_field(w: &mut dyn Write, indent: usize, field: &RustField) -> io::Result<()> {
+    writeln!(
+        w,
+        "{}{}: {}",
+        "\t".repeat(indent),
+        name(&field.id, &[]),
+        format_type(&field.ty)
+    )
+}
+
+/// Formats a name along with its generic parameters, and its serialized name if it was renamed.
+fn name(id: &Id, generic_types: &[String]) -> String {
+    let mut name = id.original.clone();
+    if !generic_types.is_empty() {
+        name = format!("{}<{}>", name, generic_types.join(", "));
+    }
+    if id.renamed != id.original {
+        name = format!("{} (as {:?})", name, id.renamed);
+    }
+    name
+}
+
+/// Formats a resolved type using Rust syntax.
+fn format_type(ty: &RustType) -> String {
+    match ty {
+        RustType::Special(SpecialRustType::Array(inner, len)) => {
+            format!("[{}; {}]", format_type(inner), len)
+        }
+        RustType::Special(SpecialRustType::Slice(inner)) => format!("[{}]", format_type(inner)),
+        _ => {
+            let parameters: Vec<String> = ty.parameters().map(format_type).collect();
+            if parameters.is_empty() {
+                ty.id().to_string()
+            } else {
+                format!("{}<{}>", ty.id(), parameters.join(", "))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use typeshare_core::parser::parse;
+
+    #[test]
+    fn lists_discovered_types() {
+        let source = r##"
+    #[typeshare]
+    #[serde(rename_all = "camelCase")]
+    pub struct Person<T> {
+        pub first_name: String,
+        pub tags: Option<Vec<T>>,
+        pub scores: HashMap<String, [u8; 3]>,
+    }
+
+    #[typeshare]
+    #[serde(tag = "type", content = "content")]
+    pub enum Shape {
+        Point,
+        Circle(f32),
+        Rect { width: u32, height: u32 },
+    }
+
+    #[typeshare]
+    pub type People = Vec<Person<String>>;
+    "##;
+
+        let mut out = Vec::new();
+        write_listing(&mut out, &parse(source).unwrap()).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "struct Person<T>
+\tfirst_name (as \"firstName\"): String
+\ttags: Option<Vec<T>>
+\tscores: HashMap<String, [u8; 3]>
+enum Shape (tag: \"type\", content: \"content\")
+\tPoint
+\tCircle(f32)
+\tRect {
+\t\twidth: u32
+\t\theight: u32
+\t}
+type People = Vec<Person<String>>
+"
+        );
+    }
+}
@@ -12,11 +12,16 @@ use typeshare_core::language::GenericConstraints;
 #[cfg(feature = "go")]
 use typeshare_core::language::Go;
 use typeshare_core::{
-    language::{Kotlin, Language, Scala, SupportedLanguage, Swift, TypeScript},
+    language::{
+        CSharp, GraphQL, JsonSchema, Kotlin, Language, Protobuf, Scala, SupportedLanguage, Swift,
+        TypeScript, Zod,
+    },
     parser::ParsedData,
 };
 
 mod config;
+mod list;
+mod watch;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -26,17 +31,42 @@ const ARG_JAVA_PACKAGE: &str = "JAVAPACKAGE";
 const ARG_MODULE_NAME: &str = "MODULENAME";
 const ARG_SCALA_PACKAGE: &str = "SCALAPACKAGE";
 const ARG_SCALA_MODULE_NAME: &str = "SCALAMODULENAME";
+const ARG_CSHARP_NAMESPACE: &str = "CSHARPNAMESPACE";
+const ARG_PROTOBUF_PACKAGE: &str = "PROTOBUFPACKAGE";
 #[cfg(feature = "go")]
 const ARG_GO_PACKAGE: &str = "GOPACKAGE";
 const ARG_CONFIG_FILE_NAME: &str = "CONFIGFILENAME";
 const ARG_GENERATE_CONFIG: &str = "generate-config-file";
 const ARG_OUTPUT_FILE: &str = "output-file";
+const ARG_WATCH: &str = "watch";
+const ARG_SORT_FIELDS: &str = "sort-fields";
 
 #[cfg(feature = "go")]
-const AVAILABLE_LANGUAGES: [&str; 5] = ["kotlin", "scala", "swift", "typescript", "go"];
+const AVAILABLE_LANGUAGES: &[&str] = &[
+    "kotlin",
+    "scala",
+    "swift",
+    "typescript",
+    "jsonschema",
+    "zod",
+    "graphql",
+    "csharp",
+    "protobuf",
+    "go",
+];
 
 #[cfg(not(feature = "go"))]
-const AVAILABLE_LANGUAGES: [&str; 4] = ["kotlin", "scala", "swift", "typescript"];
+const AVAILABLE_LANGUAGES: &[&str] = &[
+    "kotlin",
+    "scala",
+    "swift",
+    "typescript",
+    "jsonschema",
+    "zod",
+    "graphql",
+    "csharp",
+    "protobuf",
+];
 
 fn build_command() -> Command<'static> {
     command!("typeshare")
@@ -54,13 +84,23 @@ fn build_command() -> Command<'static> {
                         .possible_values(clap_complete_command::Shell::possible_values()),
                 ),
         )
+        .subcommand(
+            Command::new("list")
+                .about("List the types found in the given directories without generating any code")
+                .arg(
+                    Arg::new("directories")
+                        .help("Directories within which to recursively find and process rust files")
+                        .required(true)
+                        .min_values(1),
+                ),
+        )
         .arg(
             Arg::new(ARG_TYPE)
                 .short('l')
                 .long("lang")
                 .help("Language of generated types")
                 .takes_value(true)
-                .possible_values(AVAILABLE_LANGUAGES)
+                .possible_values(AVAILABLE_LANGUAGES.iter().copied())
                 .required_unless(ARG_GENERATE_CONFIG),
         )
         .arg(
@@ -101,6 +141,20 @@ fn build_command() -> Command<'static> {
                 .takes_value(true)
                 .required(false),
         )
+        .arg(
+            Arg::new(ARG_CSHARP_NAMESPACE)
+                .long("csharp-namespace")
+                .help("C# namespace name")
+                .takes_value(true)
+                .required(false),
+        )
+        .arg(
+            Arg::new(ARG_PROTOBUF_PACKAGE)
+                .long("protobuf-package")
+                .help("Protobuf package name")
+                .takes_value(true)
+                .required(false),
+        )
         .arg(
             Arg::new(ARG_CONFIG_FILE_NAME)
                 .short('c')
@@ -126,6 +180,21 @@ fn build_command() -> Command<'static> {
                 .takes_value(true)
                 .long(ARG_OUTPUT_FILE)
         )
+        .arg(
+            Arg::new(ARG_WATCH)
+                .short('w')
+                .long("watch")
+                .help("Regenerate the output file whenever the input files change")
+                .takes_value(false)
+                .required(false),
+        )
+        .arg(
+            Arg::new(ARG_SORT_FIELDS)
+                .long("sort-fields")
+                .help("Sort struct fields alphabetically instead of keeping their declaration order")
+                .takes_value(false)
+                .required(false),
+        )
         .arg(
             Arg::new("directories")
                 .help("Directories within which to recursively find and process rust files")
@@ -159,6 +228,18 @@ fn main() {
         return;
     }
 
+    if let Some(options) = options.subcommand_matches("list") {
+        let directories: Vec<String> = options
+            .values_of("directories")
+            .unwrap()
+            .map(String::from)
+            .collect();
+        let parsed_data =
+            parse_files(&find_rust_files(&directories)).unwrap_or_else(|error| panic!("{}", error));
+        list::write_listing(&mut std::io::stdout(), &parsed_data).expect("failed to write listing");
+        return;
+    }
+
     let config_file = options.value_of(ARG_CONFIG_FILE_NAME);
     let config = config::load_config(config_file).unwrap_or_else(|error| {
         panic!("Unable to read configuration file due to error: {}", error);
@@ -173,7 +254,11 @@ fn main() {
         return;
     }
 
-    let mut directories = options.values_of("directories").unwrap();
+    let directories: Vec<String> = options
+        .values_of("directories")
+        .unwrap()
+        .map(String::from)
+        .collect();
     let outfile = Path::new(options.value_of(ARG_OUTPUT_FILE).unwrap());
     let language_type = options
         .value_of(ARG_TYPE)
@@ -206,6 +291,28 @@ fn main() {
             type_mappings: config.typescript.type_mappings,
             ..Default::default()
         }),
+        Some(SupportedLanguage::JsonSchema) => Box::new(JsonSchema {
+            type_mappings: config.json_schema.type_mappings,
+            ..Default::default()
+        }),
+        Some(SupportedLanguage::Zod) => Box::new(Zod {
+            type_mappings: config.zod.type_mappings,
+            ..Default::default()
+        }),
+        Some(SupportedLanguage::GraphQL) => Box::new(GraphQL {
+            type_mappings: config.graphql.type_mappings,
+            ..Default::default()
+        }),
+        Some(SupportedLanguage::CSharp) => Box::new(CSharp {
+            namespace: config.csharp.namespace,
+            type_mappings: config.csharp.type_mappings,
+            ..Default::default()
+        }),
+        Some(SupportedLanguage::Protobuf) => Box::new(Protobuf {
+            package: config.protobuf.package,
+            type_mappings: config.protobuf.type_mappings,
+            ..Default::default()
+        }),
         #[cfg(feature = "go")]
         Some(SupportedLanguage::Go) => Box::new(Go {
             package: config.go.package,
@@ -222,12 +329,27 @@ fn main() {
         }
     };
 
+    let sort_fields = options.is_present(ARG_SORT_FIELDS);
+
+    if options.is_present(ARG_WATCH) {
+        let roots = directories.clone();
+        let events = watch::poll_changes(watch::POLL_INTERVAL, move || find_rust_files(&roots));
+        watch::watch(&events, watch::DEBOUNCE, || {
+            generate(lang.as_mut(), &directories, outfile, sort_fields)
+        });
+    } else if let Err(error) = generate(lang.as_mut(), &directories, outfile, sort_fields) {
+        panic!("{}", error);
+    }
+}
+
+/// Finds every rust file within `directories`.
+fn find_rust_files(directories: &[String]) -> Vec<String> {
     let mut types = TypesBuilder::new();
     types.add("rust", "*.rs").unwrap();
     types.select("rust");
 
     // This is guaranteed to always have at least one value by the clap configuration
-    let first_root = directories.next().unwrap();
+    let first_root = &directories[0];
 
     let overrides = OverrideBuilder::new(first_root)
         // Don't process files inside of tools/typeshare/
@@ -242,7 +364,7 @@ fn main() {
     walker_builder.types(types.build().expect("Failed to build types"));
     walker_builder.overrides(overrides);
 
-    for root in directories {
+    for root in &directories[1..] {
         walker_builder.add(root);
     }
 
@@ -250,37 +372,53 @@ fn main() {
     // a git-ignored directory to be processed, add the specific directory to
     // the list of directories given to typeshare when it's invoked in the
     // makefiles
-    let glob_paths: Vec<String> = walker_builder
+    walker_builder
         .build()
         .filter_map(Result::ok)
         .filter(|dir_entry| !dir_entry.path().is_dir())
         .filter_map(|dir_entry| dir_entry.path().to_str().map(String::from))
-        .collect();
+        .collect()
+}
 
-    let mut generated_contents = vec![];
-    let parsed_data = glob_paths
+/// Parses all of the given rust files into a single `ParsedData`.
+fn parse_files(glob_paths: &[String]) -> Result<ParsedData, String> {
+    glob_paths
         .par_iter()
         .map(|filepath| {
-            let data = std::fs::read_to_string(filepath).unwrap_or_else(|e| {
-                panic!(
+            let data = fs::read_to_string(filepath).map_err(|e| {
+                format!(
                     "failed to read file at {filepath:?}: {e}",
                     filepath = filepath,
                     e = e
                 )
-            });
-            let parsed_data = typeshare_core::parser::parse(&data);
-            if parsed_data.is_err() {
-                panic!("{}", parsed_data.err().unwrap());
-            }
-            parsed_data.unwrap()
+            })?;
+            typeshare_core::parser::parse(&data).map_err(|e| e.to_string())
         })
-        .reduce(ParsedData::default, |mut identity, other| {
-            identity.add(other);
-            identity
-        });
+        .reduce(
+            || Ok(ParsedData::default()),
+            |identity, other| {
+                let mut identity = identity?;
+                identity.add(other?);
+                Ok(identity)
+            },
+        )
+}
 
+/// Parses the rust files within `directories` and writes the generated types to `outfile`.
+fn generate(
+    lang: &mut dyn Language,
+    directories: &[String],
+    outfile: &Path,
+    sort_fields: bool,
+) -> Result<(), String> {
+    let mut parsed_data = parse_files(&find_rust_files(directories))?;
+    if sort_fields {
+        parsed_data.sort_fields();
+    }
+
+    let mut generated_contents = vec![];
     lang.generate_types(&mut generated_contents, &parsed_data)
-        .expect("Couldn't generate types");
+        .map_err(|e| format!("Couldn't generate types: {}", e))?;
 
     match fs::read(outfile) {
         Ok(buf) if buf == generated_contents => {
@@ -288,7 +426,7 @@ fn main() {
             // avoid writing the file to leave the mtime intact
             // for tools which might use it to know when to
             // rebuild.
-            return;
+            return Ok(());
         }
         _ => {}
     }
@@ -296,10 +434,11 @@ fn main() {
     let out_dir = outfile.parent().unwrap();
     // If the output directory doesn't already exist, create it.
     if !out_dir.exists() {
-        fs::create_dir_all(out_dir).expect("failed to create output directory");
+        fs::create_dir_all(out_dir)
+            .map_err(|e| format!("failed to create output directory: {}", e))?;
     }
 
-    fs::write(outfile, generated_contents).expect("failed to write output");
+    fs::write(outfile, generated_contents).map_err(|e| format!("failed to write output: {}", e))
 }
 
 /// Overrides any configuration values with provided arguments
@@ -324,6 +463,14 @@ fn override_configuration(mut config: Config, options: &ArgMatches) -> Config {
         config.scala.module_name = scala_module_name.to_string();
     }
 
+    if let Some(csharp_namespace) = options.value_of(ARG_CSHARP_NAMESPACE) {
+        config.csharp.namespace = csharp_namespace.to_string();
+    }
+
+    if let Some(protobuf_package) = options.value_of(ARG_PROTOBUF_PACKAGE) {
+        config.protobuf.package = protobuf_package.to_string();
+    }
+
     #[cfg(feature = "go")]
     if let Some(go_package) = options.value_of(ARG_GO_PACKAGE) {
         config.go.package = go_package.to_string();
@@ -0,0 +1,124 @@
+//! Regenerates the output whenever the input files change.
+//!
+//! Changes are found by polling the modification times of the input files rather than through
+//! filesystem notifications, so that watching needs no extra dependency. The cost is latency: a
+//! change is picked up within [`POLL_INTERVAL`], and regeneration starts [`DEBOUNCE`] after the
+//! last change of a burst, so output can lag a save by up to about 700ms. Every poll also walks
+//! the input directories again, which is noticeable on very large trees.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    sync::mpsc::{self, Receiver},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// How often the input files are checked for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// Changes arriving within this long of each other only trigger a single regeneration.
+pub const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Spawns a thread that checks the files returned by `list_files` every `interval`, and sends
+/// an event whenever a file was added, removed or modified since the last check.
+///
+/// The thread stops once the returned receiver is dropped.
+pub fn poll_changes<F>(interval: Duration, list_files: F) -> Receiver<()>
+where
+    F: Fn() -> Vec<String> + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let mut last_snapshot = snapshot(list_files());
+        loop {
+            thread::sleep(interval);
+            let snapshot = snapshot(list_files());
+            if snapshot != last_snapshot {
+                if sender.send(()).is_err() {
+                    break;
+                }
+                last_snapshot = snapshot;
+            }
+        }
+    });
+
+    receiver
+}
+
+/// Runs `generate` once, and then again for every burst of `events`, until the sending side
+/// of `events` is dropped.
+///
+/// Errors are reported on stderr without stopping the watcher, so they can be fixed in place.
+pub fn watch<F>(events: &Receiver<()>, debounce: Duration, mut generate: F)
+where
+    F: FnMut() -> Result<(), String>,
+{
+    report(generate());
+
+    while events.recv().is_ok() {
+        // Wait for the changes to settle, e.g. when several files are saved at once.
+        while events.recv_timeout(debounce).is_ok() {}
+        report(generate());
+    }
+}
+
+fn report(result: Result<(), String>) {
+    match result {
+        Ok(()) => eprintln!("Generated types, watching for changes..."),
+        Err(error) => eprintln!("Failed to generate types: {}", error),
+    }
+}
+
+/// The last modification time of every file, if it could be read.
+fn snapshot(files: Vec<String>) -> BTreeMap<String, Option<SystemTime>> {
+    files
+        .into_iter()
+        .map(|file| {
+            let modified = fs::metadata(&file).and_then(|m| m.modified()).ok();
+            (file, modified)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn regenerates_on_every_change() {
+        let (sender, events) = mpsc::channel();
+        let mut sender = Some(sender);
+
+        let mut runs = 0;
+        watch(&events, Duration::from_millis(10), || {
+            runs += 1;
+            // Simulate a file changing after each of the first two runs.
+            if runs < 3 {
+                sender.as_ref().unwrap().send(()).unwrap();
+            } else {
+                sender = None;
+            }
+            Err("errors don't stop the watcher".to_string())
+        });
+
+        assert_eq!(runs, 3);
+    }
+
+    #[test]
+    fn debounces_bursts_of_changes() {
+        let (sender, events) = mpsc::channel();
+        for _ in 0..5 {
+            sender.send(()).unwrap();
+        }
+        drop(sender);
+
+        let mut runs = 0;
+        watch(&events, Duration::from_millis(10), || {
+            runs += 1;
+            Ok(())
+        });
+
+        assert_eq!(runs, 2);
+    }
+}
@@ -0,0 +1,38 @@
+/// A person
+#[typeshare]
+#[serde(rename_all = "camelCase")]
+pub struct Person {
+    pub first_name: String,
+    /// Age in years
+    pub age: u8,
+    pub nickname: Option<String>,
+    pub emails: Emails,
+    pub scores: HashMap<String, f64>,
+    pub address: Option<Address>,
+    #[serde(default)]
+    pub verified: bool,
+}
+
+#[typeshare]
+pub struct Address {
+    pub street: String,
+    pub lines: Option<Vec<Option<String>>>,
+}
+
+#[typeshare]
+pub type Emails = Vec<String>;
+
+#[typeshare]
+pub struct Empty {}
+
+#[typeshare]
+pub struct Email {
+    pub email: String,
+}
+
+#[typeshare]
+pub enum Colors {
+    Red,
+    #[serde(rename = "blue-ish")]
+    Blue,
+}
@@ -0,0 +1,56 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json.Serialization;
+
+namespace Company.Models;
+
+public record Address
+{
+	[JsonPropertyName("street")]
+	public required string Street { get; init; }
+	[JsonPropertyName("lines")]
+	public List<string?>? Lines { get; init; }
+}
+
+/// <summary>
+/// A person
+/// </summary>
+public record Person
+{
+	[JsonPropertyName("firstName")]
+	public required string FirstName { get; init; }
+	/// <summary>
+	/// Age in years
+	/// </summary>
+	[JsonPropertyName("age")]
+	public required byte Age { get; init; }
+	[JsonPropertyName("nickname")]
+	public string? Nickname { get; init; }
+	[JsonPropertyName("emails")]
+	public required List<string> Emails { get; init; }
+	[JsonPropertyName("scores")]
+	public required Dictionary<string, double> Scores { get; init; }
+	[JsonPropertyName("address")]
+	public Address? Address { get; init; }
+	[JsonPropertyName("verified")]
+	public bool? Verified { get; init; }
+}
+
+public record Empty;
+
+public record Email
+{
+	[JsonPropertyName("email")]
+	public required string EmailValue { get; init; }
+}
+
+[JsonConverter(typeof(JsonStringEnumConverter))]
+public enum Colors
+{
+	[JsonStringEnumMemberName("Red")]
+	Red,
+	[JsonStringEnumMemberName("blue-ish")]
+	Blue,
+}
+
@@ -0,0 +1,27 @@
+/// A person
+#[typeshare]
+pub struct Person {
+    pub name: String,
+    /// Age in years
+    pub age: u8,
+    pub nickname: Option<String>,
+    pub emails: Vec<String>,
+    pub address: Address,
+}
+
+#[typeshare]
+pub struct Address {
+    pub street: String,
+    pub lines: Option<Vec<Option<String>>>,
+}
+
+#[typeshare]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Colors {
+    Red,
+    /// Not quite blue
+    LightBlue,
+}
+
+#[typeshare]
+pub type PersonId = String;
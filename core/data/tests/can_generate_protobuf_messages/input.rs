@@ -0,0 +1,27 @@
+/// A person
+#[typeshare]
+pub struct Person {
+    pub name: String,
+    /// Age in years
+    pub age: Option<u8>,
+    pub emails: Vec<String>,
+    pub scores: HashMap<String, f64>,
+    pub addresses: Option<Vec<Address>>,
+    pub favorite_color: Colors,
+}
+
+#[typeshare]
+pub struct Address {
+    pub street: String,
+    pub lines: HashSet<String>,
+}
+
+#[typeshare]
+pub struct Empty {}
+
+#[typeshare]
+pub enum Colors {
+    Red,
+    /// Not quite blue
+    LightBlue,
+}
@@ -0,0 +1,5 @@
+#[typeshare]
+pub struct Tags {
+    pub unique: HashSet<String>,
+    pub sorted: BTreeSet<i32>,
+}
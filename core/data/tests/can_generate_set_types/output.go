@@ -0,0 +1,8 @@
+package proto
+
+import "encoding/json"
+
+type Tags struct {
+	Unique []string `json:"unique"`
+	Sorted []int `json:"sorted"`
+}
@@ -0,0 +1,13 @@
+@file:NoLiveLiterals
+
+package com.agilebits.onepassword
+
+import androidx.compose.runtime.NoLiveLiterals
+import kotlinx.serialization.*
+
+@Serializable
+data class Tags (
+	val unique: Set<String>,
+	val sorted: Set<Int>
+)
+
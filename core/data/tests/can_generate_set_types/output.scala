@@ -0,0 +1,10 @@
+package com.agilebits
+
+package onepassword {
+
+case class Tags (
+	unique: Set[String],
+	sorted: Set[Int]
+)
+
+}
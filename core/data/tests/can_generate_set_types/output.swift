@@ -0,0 +1,11 @@
+import Foundation
+
+public struct Tags: Codable {
+	public let unique: [String]
+	public let sorted: [Int32]
+
+	public init(unique: [String], sorted: [Int32]) {
+		self.unique = unique
+		self.sorted = sorted
+	}
+}
@@ -0,0 +1,5 @@
+export interface Tags {
+	unique: string[];
+	sorted: number[];
+}
+
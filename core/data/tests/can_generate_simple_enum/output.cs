@@ -0,0 +1,23 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json.Serialization;
+
+/// <summary>
+/// This is a comment.
+/// Continued lovingly here
+/// </summary>
+[JsonConverter(typeof(JsonStringEnumConverter))]
+public enum Colors
+{
+	[JsonStringEnumMemberName("Red")]
+	Red,
+	[JsonStringEnumMemberName("Blue")]
+	Blue,
+	/// <summary>
+	/// Green is a cool color
+	/// </summary>
+	[JsonStringEnumMemberName("Green")]
+	Green,
+}
+
@@ -0,0 +1,14 @@
+/**
+ * This is a block comment.
+ * It spans several lines.
+ */
+#[typeshare]
+pub struct Person {
+    /**
+     * The name of the person.
+     * Empty if it isn't known.
+     */
+    pub name: String,
+    /** The age of the person. */
+    pub age: u8,
+}
@@ -0,0 +1,18 @@
+@file:NoLiveLiterals
+
+package com.agilebits.onepassword
+
+import androidx.compose.runtime.NoLiveLiterals
+import kotlinx.serialization.*
+
+/// This is a block comment.
+/// It spans several lines.
+@Serializable
+data class Person (
+	/// The name of the person.
+	/// Empty if it isn't known.
+	val name: String,
+	/// The age of the person.
+	val age: UByte
+)
+
@@ -0,0 +1,14 @@
+/**
+ * This is a block comment.
+ * It spans several lines.
+ */
+export interface Person {
+	/**
+	 * The name of the person.
+	 * Empty if it isn't known.
+	 */
+	name: string;
+	/** The age of the person. */
+	age: number;
+}
+
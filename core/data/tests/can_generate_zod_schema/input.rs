@@ -0,0 +1,40 @@
+/// A person
+#[typeshare]
+pub struct Person {
+    pub name: String,
+    pub age: u8,
+    pub nickname: Option<String>,
+    pub emails: Vec<String>,
+    pub scores: HashMap<String, f64>,
+    pub ranks: HashMap<u32, String>,
+    pub address: Address,
+}
+
+#[typeshare]
+pub struct Address {
+    pub street: String,
+}
+
+#[typeshare]
+pub struct Tree {
+    pub value: String,
+    pub children: Vec<Tree>,
+}
+
+#[typeshare]
+pub enum Colors {
+    Red,
+    #[serde(rename = "blue-ish")]
+    Blue,
+}
+
+#[typeshare]
+#[serde(tag = "type", content = "content")]
+pub enum Shape {
+    Point,
+    Circle(f32),
+    Rect { width: u32, height: u32 },
+}
+
+#[typeshare]
+pub type PersonId = String;
@@ -0,0 +1,38 @@
+import { z } from "zod";
+
+export const PersonId = z.string();
+export type PersonId = z.infer<typeof PersonId>;
+
+export const Address = z.object({
+	street: z.string(),
+});
+export type Address = z.infer<typeof Address>;
+
+/** A person */
+export const Person = z.object({
+	name: z.string(),
+	age: z.number().int().nonnegative(),
+	nickname: z.string().nullable().optional(),
+	emails: z.array(z.string()),
+	scores: z.record(z.string(), z.number()),
+	ranks: z.record(z.coerce.number().int().nonnegative(), z.string()),
+	address: Address,
+});
+export type Person = z.infer<typeof Person>;
+
+export const Tree: z.ZodType<Tree> = z.object({
+	value: z.string(),
+	children: z.array(z.lazy(() => Tree)),
+});
+export type Tree = { value: string; children: Tree[] };
+
+export const Colors = z.enum(["Red", "blue-ish"]);
+export type Colors = z.infer<typeof Colors>;
+
+export const Shape = z.discriminatedUnion("type", [
+	z.object({ type: z.literal("Point") }),
+	z.object({ type: z.literal("Circle"), content: z.number() }),
+	z.object({ type: z.literal("Rect"), content: z.object({ width: z.number().int().nonnegative(), height: z.number().int().nonnegative() }) }),
+]);
+export type Shape = z.infer<typeof Shape>;
+
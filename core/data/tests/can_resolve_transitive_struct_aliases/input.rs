@@ -0,0 +1,18 @@
+#[typeshare]
+#[serde(tag = "type", content = "content")]
+pub enum Colors {
+    Red,
+    Blue,
+    Green(A),
+}
+
+#[typeshare]
+pub type A = B;
+
+#[typeshare]
+pub type B = SomeStruct;
+
+#[typeshare]
+pub struct SomeStruct {
+    field: u32,
+}
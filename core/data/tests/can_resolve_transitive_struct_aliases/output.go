@@ -0,0 +1,81 @@
+package proto
+
+import "encoding/json"
+
+type SomeStruct struct {
+	Field uint32 `json:"field"`
+}
+type B SomeStruct
+
+type A B
+
+type ColorsTypes string
+const (
+	ColorsTypeVariantRed ColorsTypes = "Red"
+	ColorsTypeVariantBlue ColorsTypes = "Blue"
+	ColorsTypeVariantGreen ColorsTypes = "Green"
+)
+type Colors struct{ 
+	Type ColorsTypes `json:"type"`
+	content interface{}
+}
+
+func (c *Colors) UnmarshalJSON(data []byte) error {
+	var enum struct {
+		Tag    ColorsTypes   `json:"type"`
+		Content json.RawMessage `json:"content"`
+	}
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+
+	c.Type = enum.Tag
+	switch c.Type {
+	case ColorsTypeVariantRed:
+		return nil
+	case ColorsTypeVariantBlue:
+		return nil
+	case ColorsTypeVariantGreen:
+		var res A
+		c.content = &res
+
+	}
+	if err := json.Unmarshal(enum.Content, &c.content); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (c Colors) MarshalJSON() ([]byte, error) {
+    var enum struct {
+		Tag    ColorsTypes   `json:"type"`
+		Content interface{} `json:"content,omitempty"`
+    }
+    enum.Tag = c.Type
+    enum.Content = c.content
+    return json.Marshal(enum)
+}
+
+func (c Colors) Green() *A {
+	res, _ := c.content.(*A)
+	return res
+}
+
+func NewColorsTypeVariantRed() Colors {
+    return Colors{
+        Type: ColorsTypeVariantRed,
+    }
+}
+func NewColorsTypeVariantBlue() Colors {
+    return Colors{
+        Type: ColorsTypeVariantBlue,
+    }
+}
+func NewColorsTypeVariantGreen(content *A) Colors {
+    return Colors{
+        Type: ColorsTypeVariantGreen,
+        content: content,
+    }
+}
+
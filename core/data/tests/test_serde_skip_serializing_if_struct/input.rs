@@ -0,0 +1,8 @@
+#[typeshare]
+#[serde(rename_all = "camelCase")]
+pub struct Foo {
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub bar: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baz: Option<String>,
+}
@@ -0,0 +1,8 @@
+package proto
+
+import "encoding/json"
+
+type Foo struct {
+	Bar *bool `json:"bar,omitempty"`
+	Baz *string `json:"baz,omitempty"`
+}
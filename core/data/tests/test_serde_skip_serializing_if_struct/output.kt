@@ -0,0 +1,13 @@
+@file:NoLiveLiterals
+
+package com.agilebits.onepassword
+
+import androidx.compose.runtime.NoLiveLiterals
+import kotlinx.serialization.*
+
+@Serializable
+data class Foo (
+	val bar: Boolean? = null,
+	val baz: String? = null
+)
+
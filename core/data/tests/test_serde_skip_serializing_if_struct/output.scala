@@ -0,0 +1,10 @@
+package com.agilebits
+
+package onepassword {
+
+case class Foo (
+	bar: Boolean = _,
+	baz: Option[String] = None
+)
+
+}
@@ -0,0 +1,11 @@
+import Foundation
+
+public struct Foo: Codable {
+	public let bar: Bool?
+	public let baz: String?
+
+	public init(bar: Bool?, baz: String?) {
+		self.bar = bar
+		self.baz = baz
+	}
+}
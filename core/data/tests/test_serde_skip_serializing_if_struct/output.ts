@@ -0,0 +1,5 @@
+export interface Foo {
+	bar?: boolean;
+	baz?: string;
+}
+
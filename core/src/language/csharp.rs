@@ -0,0 +1,295 @@
+use crate::parser::ParsedData;
+use crate::rust_types::{RustItem, RustTypeFormatError, SpecialRustType};
+use crate::{
+    language::{Language, LanguageFeature, SupportedLanguage},
+    rename::RenameExt,
+    rust_types::{RustEnum, RustField, RustStruct},
+    topsort::topsort,
+    GenerationError,
+};
+use std::io;
+use std::{collections::HashMap, io::Write};
+
+/// All information needed to generate C# type-code
+///
+/// Structs become records whose properties are serialized with `System.Text.Json`.
+/// C# aliases are local to a single file, so type aliases are not written out: every
+/// reference to one is replaced by the aliased type instead.
+#[derive(Default)]
+pub struct CSharp {
+    /// Name of the C# namespace
+    pub namespace: String,
+    /// Conversions from Rust type names to C# type names.
+    pub type_mappings: HashMap<String, String>,
+    /// Whether or not to exclude the version header that normally appears at the top of generated code.
+    /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
+    pub no_version_header: bool,
+}
+
+impl Language for CSharp {
+    fn generate_types(
+        &mut self,
+        w: &mut dyn Write,
+        data: &ParsedData,
+    ) -> Result<(), GenerationError> {
+        let mut items: Vec<RustItem> = vec![];
+
+        for a in &data.aliases {
+            items.push(RustItem::Alias(a.clone()))
+        }
+
+        for s in &data.structs {
+            items.push(RustItem::Struct(s.clone()))
+        }
+
+        for e in &data.enums {
+            items.push(RustItem::Enum(e.clone()))
+        }
+
+        let sorted = topsort(items.iter().collect());
+
+        // Inlined aliases are only added to the type mappings while this file is generated
+        let type_mappings = self.type_mappings.clone();
+        let result = self.write_items(w, &sorted);
+        self.type_mappings = type_mappings;
+        result
+    }
+
+    fn type_map(&mut self) -> &HashMap<String, String> {
+        &self.type_mappings
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "cs"
+    }
+
+    fn supports(&self, feature: LanguageFeature) -> bool {
+        !matches!(feature, LanguageFeature::AlgebraicEnums)
+    }
+
+    fn format_special_type(
+        &mut self,
+        special_ty: &SpecialRustType,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        Ok(match special_ty {
+            SpecialRustType::Vec(rtype)
+            | SpecialRustType::Array(rtype, _)
+            | SpecialRustType::Slice(rtype) => {
+                format!("List<{}>", self.format_type(rtype, generic_types)?)
+            }
+            SpecialRustType::HashSet(rtype) => {
+                format!("HashSet<{}>", self.format_type(rtype, generic_types)?)
+            }
+            SpecialRustType::Option(rtype) => {
+                let formatted_type = self.format_type(rtype, generic_types)?;
+                // `T??` is not valid C#, nested options collapse into a single nullable type
+                if formatted_type.ends_with('?') {
+                    formatted_type
+                } else {
+                    format!("{}?", formatted_type)
+                }
+            }
+            SpecialRustType::HashMap(rtype1, rtype2) => format!(
+                "Dictionary<{}, {}>",
+                self.format_type(rtype1, generic_types)?,
+                self.format_type(rtype2, generic_types)?
+            ),
+            SpecialRustType::Unit => {
+                return Err(RustTypeFormatError::UnsupportedTypeInCSharp(
+                    special_ty.id().to_string(),
+                ))
+            }
+            // Char in C# is 16 bits long, so we need to use string
+            SpecialRustType::String | SpecialRustType::Char => "string".into(),
+            SpecialRustType::I8 => "sbyte".into(),
+            SpecialRustType::I16 => "short".into(),
+            SpecialRustType::I32 => "int".into(),
+            SpecialRustType::ISize | SpecialRustType::I54 | SpecialRustType::I64 => "long".into(),
+            SpecialRustType::U8 => "byte".into(),
+            SpecialRustType::U16 => "ushort".into(),
+            SpecialRustType::U32 => "uint".into(),
+            SpecialRustType::USize | SpecialRustType::U53 | SpecialRustType::U64 => "ulong".into(),
+            SpecialRustType::Bool => "bool".into(),
+            SpecialRustType::F32 => "float".into(),
+            SpecialRustType::F64 => "double".into(),
+        })
+    }
+
+    fn begin_file(&mut self, w: &mut dyn Write) -> io::Result<()> {
+        if !self.no_version_header {
+            writeln!(w, "// Generated by typeshare {}", env!("CARGO_PKG_VERSION"))?;
+            writeln!(w)?;
+        }
+        // Nullable types such as `string?` are only annotations within a nullable context
+        writeln!(w, "#nullable enable")?;
+        writeln!(w)?;
+        writeln!(w, "using System.Collections.Generic;")?;
+        writeln!(w, "using System.Text.Json.Serialization;")?;
+        writeln!(w)?;
+        if !self.namespace.is_empty() {
+            writeln!(w, "namespace {};", self.namespace)?;
+            writeln!(w)?;
+        }
+        Ok(())
+    }
+
+    fn write_struct(&mut self, w: &mut dyn Write, rs: &RustStruct) -> io::Result<()> {
+        self.write_comments(w, 0, &rs.comments)?;
+
+        let generic_parameters = (!rs.generic_types.is_empty())
+            .then(|| format!("<{}>", rs.generic_types.join(", ")))
+            .unwrap_or_default();
+
+        if rs.fields.is_empty() {
+            return writeln!(
+                w,
+                "public record {}{};\n",
+                rs.id.renamed, generic_parameters
+            );
+        }
+
+        writeln!(w, "public record {}{}", rs.id.renamed, generic_parameters)?;
+        writeln!(w, "{{")?;
+
+        rs.fields.iter().try_for_each(|f| {
+            self.write_property(w, f, &rs.id.renamed, rs.generic_types.as_slice())
+        })?;
+
+        writeln!(w, "}}\n")
+    }
+
+    fn write_enum(&mut self, w: &mut dyn Write, e: &RustEnum) -> io::Result<()> {
+        match e {
+            RustEnum::Unit(shared) => {
+                self.write_comments(w, 0, &shared.comments)?;
+                writeln!(w, "[JsonConverter(typeof(JsonStringEnumConverter))]")?;
+                writeln!(w, "public enum {}", shared.id.renamed)?;
+                writeln!(w, "{{")?;
+
+                for v in &shared.variants {
+                    self.write_comments(w, 1, &v.shared().comments)?;
+                    writeln!(
+                        w,
+                        "\t[JsonStringEnumMemberName({:?})]",
+                        v.shared().id.renamed
+                    )?;
+                    writeln!(w, "\t{},", v.shared().id.original.to_pascal_case())?;
+                }
+
+                writeln!(w, "}}\n")
+            }
+            RustEnum::Algebraic { shared, .. } => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "algebraic enum `{}` cannot be represented in C#",
+                    shared.id.original
+                ),
+            )),
+        }
+    }
+}
+
+impl CSharp {
+    fn write_items(
+        &mut self,
+        w: &mut dyn Write,
+        items: &[&RustItem],
+    ) -> Result<(), GenerationError> {
+        // Dependencies are sorted first, so aliases of aliases resolve to the final type
+        for &item in items {
+            if let RustItem::Alias(a) = item {
+                if !a.generic_types.is_empty() {
+                    return Err(GenerationError::UnsupportedConstruct {
+                        type_name: a.id.original.clone(),
+                        reason: "generic type aliases cannot be represented in C#".to_string(),
+                    });
+                }
+                if !self.type_mappings.contains_key(&a.id.original) {
+                    let ty = self.format_type(&a.r#type, &[]).map_err(|source| {
+                        GenerationError::UnmappableType {
+                            type_name: a.id.original.clone(),
+                            source,
+                        }
+                    })?;
+                    self.type_mappings.insert(a.id.original.clone(), ty);
+                }
+            }
+        }
+
+        self.begin_file(w)?;
+
+        for &item in items {
+            let result = match item {
+                RustItem::Enum(e) => self.write_enum(w, e),
+                RustItem::Struct(s) => self.write_struct(w, s),
+                RustItem::Alias(_) => Ok(()),
+            };
+            result.map_err(|e| GenerationError::for_type(e, &item.id().original))?;
+        }
+
+        self.end_file(w)?;
+
+        Ok(())
+    }
+
+    fn write_property(
+        &mut self,
+        w: &mut dyn Write,
+        field: &RustField,
+        record_name: &str,
+        generic_types: &[String],
+    ) -> io::Result<()> {
+        self.write_comments(w, 1, &field.comments)?;
+        let mut ty = match field.type_override(SupportedLanguage::CSharp) {
+            Some(type_override) => type_override.to_owned(),
+            None => self
+                .format_type(&field.ty, generic_types)
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?,
+        };
+
+        // Fields that may be missing are nullable, every other field has to be set
+        let required = if field.ty.is_optional() {
+            ""
+        } else if field.may_be_absent() {
+            ty.push('?');
+            ""
+        } else {
+            "required "
+        };
+
+        // Members can't share the name of their enclosing type
+        let mut name = field.id.original.to_pascal_case();
+        if name == record_name {
+            name.push_str("Value");
+        }
+
+        writeln!(w, "\t[JsonPropertyName({:?})]", field.id.renamed)?;
+        writeln!(w, "\tpublic {}{} {} {{ get; init; }}", required, ty, name)
+    }
+
+    fn write_comments(
+        &self,
+        w: &mut dyn Write,
+        indent: usize,
+        comments: &[String],
+    ) -> io::Result<()> {
+        if !comments.is_empty() {
+            let tab_indent = "\t".repeat(indent);
+            writeln!(w, "{}/// <summary>", tab_indent)?;
+            for comment in comments {
+                writeln!(w, "{}/// {}", tab_indent, escape_xml(comment))?;
+            }
+            writeln!(w, "{}/// </summary>", tab_indent)?;
+        }
+        Ok(())
+    }
+}
+
+/// Escapes the characters that have a special meaning in XML documentation comments.
+fn escape_xml(comment: &str) -> String {
+    comment
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
@@ -1,6 +1,6 @@
 use std::io::Write;
 
-use crate::language::SupportedLanguage;
+use crate::language::{LanguageFeature, SupportedLanguage};
 use crate::parser::ParsedData;
 use crate::rename::RenameExt;
 use crate::rust_types::{RustItem, RustTypeFormatError, SpecialRustType};
@@ -8,6 +8,7 @@ use crate::{
     language::Language,
     rust_types::{RustEnum, RustEnumVariant, RustField, RustStruct, RustTypeAlias},
     topsort::topsort,
+    GenerationError,
 };
 use std::collections::{HashMap, HashSet};
 
@@ -26,16 +27,28 @@ pub struct Go {
 }
 
 impl Language for Go {
-    fn generate_types(&mut self, w: &mut dyn Write, data: &ParsedData) -> std::io::Result<()> {
+    fn generate_types(
+        &mut self,
+        w: &mut dyn Write,
+        data: &ParsedData,
+    ) -> Result<(), GenerationError> {
         // Generate a list of all types that either are a struct or are aliased to a struct.
         // This is used to determine whether a type should be defined as a pointer or not.
         let mut types_mapping_to_struct = HashSet::new();
         for s in &data.structs {
             types_mapping_to_struct.insert(s.id.original.as_str());
         }
-        for alias in &data.aliases {
-            if types_mapping_to_struct.contains(&alias.r#type.id()) {
-                types_mapping_to_struct.insert(alias.id.original.as_str());
+        // Aliases can point at other aliases, so keep resolving until no new
+        // alias is found to map to a struct.
+        loop {
+            let mut changed = false;
+            for alias in &data.aliases {
+                if types_mapping_to_struct.contains(&alias.r#type.id()) {
+                    changed |= types_mapping_to_struct.insert(alias.id.original.as_str());
+                }
+            }
+            if !changed {
+                break;
             }
         }
 
@@ -58,11 +71,12 @@ impl Language for Go {
         let sorted = topsort(items.iter().collect());
 
         for &thing in &sorted {
-            match thing {
-                RustItem::Enum(e) => self.write_enum(w, e, &types_mapping_to_struct)?,
-                RustItem::Struct(s) => self.write_struct(w, s)?,
-                RustItem::Alias(a) => self.write_type_alias(w, a)?,
-            }
+            let result = match thing {
+                RustItem::Enum(e) => self.write_enum(w, e, &types_mapping_to_struct),
+                RustItem::Struct(s) => self.write_struct(w, s),
+                RustItem::Alias(a) => self.write_type_alias(w, a),
+            };
+            result.map_err(|e| GenerationError::for_type(e, &thing.id().original))?;
         }
 
         self.end_file(w)?;
@@ -74,6 +88,15 @@ impl Language for Go {
         &self.type_mappings
     }
 
+    fn file_extension(&self) -> &'static str {
+        "go"
+    }
+
+    fn supports(&self, feature: LanguageFeature) -> bool {
+        // Generic parameters are dropped when writing Go types.
+        !matches!(feature, LanguageFeature::Generics)
+    }
+
     fn format_special_type(
         &mut self,
         special_ty: &SpecialRustType,
@@ -87,6 +110,9 @@ impl Language for Go {
             SpecialRustType::Slice(rtype) => {
                 format!("[]{}", self.format_type(rtype, generic_types)?)
             }
+            SpecialRustType::HashSet(rtype) => {
+                format!("[]{}", self.format_type(rtype, generic_types)?)
+            }
             SpecialRustType::Option(rtype) => {
                 format!("*{}", self.format_type(rtype, generic_types)?)
             }
@@ -236,9 +262,10 @@ impl Go {
                 for v in &shared.variants {
                     let variant_name = self.acronyms_to_uppercase(&v.shared().id.original);
                     let variant_type = match v {
-                        RustEnumVariant::Tuple { ty, .. } => {
-                            Some(self.format_type(ty, &[]).unwrap())
-                        }
+                        RustEnumVariant::Tuple { ty, .. } => Some(
+                            self.format_type(ty, &[])
+                                .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?,
+                        ),
                         RustEnumVariant::AnonymousStruct { .. } => {
                             Some(make_anonymous_struct_name(&variant_name))
                         }
@@ -415,14 +442,14 @@ func ({short_name} {full_name}) MarshalJSON() ([]byte, error) {{
         };
 
         let go_type = self.acronyms_to_uppercase(&type_name);
-        let is_optional = field.ty.is_optional() || field.has_default;
+        let is_optional = field.ty.is_optional() || field.may_be_absent();
         let formatted_renamed_id = format!("{:?}", &field.id.renamed);
         let renamed_id = &formatted_renamed_id[1..formatted_renamed_id.len() - 1];
         writeln!(
             w,
             "\t{} {}{} `json:\"{}{}\"`",
             self.format_field_name(field.id.original.to_string(), true),
-            (field.has_default && !field.ty.is_optional())
+            (field.may_be_absent() && !field.ty.is_optional())
                 .then(|| "*")
                 .unwrap_or_default(),
             go_type,
@@ -0,0 +1,279 @@
+use crate::parser::ParsedData;
+use crate::rust_types::{RustItem, RustType, RustTypeFormatError, SpecialRustType};
+use crate::{
+    language::{Language, LanguageFeature, SupportedLanguage},
+    rust_types::{RustEnum, RustField, RustStruct, RustTypeAlias},
+    topsort::topsort,
+    GenerationError,
+};
+use std::io;
+use std::{collections::HashMap, io::Write};
+
+/// All information needed to generate a GraphQL schema definition
+///
+/// GraphQL has no type aliases, so aliases are declared as custom scalars.
+/// Every struct is written out as an object type, and as an input type named
+/// `<Name>Input` so that it can be used as an argument as well.
+#[derive(Default)]
+pub struct GraphQL {
+    /// Mappings from Rust type names to GraphQL type names
+    pub type_mappings: HashMap<String, String>,
+    /// Whether or not to exclude the version header that normally appears at the top of generated code.
+    /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
+    pub no_version_header: bool,
+}
+
+impl Language for GraphQL {
+    fn generate_types(
+        &mut self,
+        w: &mut dyn Write,
+        data: &ParsedData,
+    ) -> Result<(), GenerationError> {
+        self.begin_file(w)?;
+
+        let mut items: Vec<RustItem> = vec![];
+
+        for a in &data.aliases {
+            items.push(RustItem::Alias(a.clone()))
+        }
+
+        for s in &data.structs {
+            items.push(RustItem::Struct(s.clone()))
+        }
+
+        for e in &data.enums {
+            items.push(RustItem::Enum(e.clone()))
+        }
+
+        let sorted = topsort(items.iter().collect());
+
+        for &thing in &sorted {
+            let result = match thing {
+                RustItem::Enum(e) => self.write_enum(w, e),
+                RustItem::Struct(s) => self.write_struct(w, s),
+                RustItem::Alias(a) => self.write_type_alias(w, a),
+            };
+            result.map_err(|e| GenerationError::for_type(e, &thing.id().original))?;
+        }
+
+        // Input types can't refer to object types, so references to structs are only
+        // mapped to their input counterparts while those are written
+        let type_mappings = self.type_mappings.clone();
+        for s in &data.structs {
+            self.type_mappings
+                .entry(s.id.original.clone())
+                .or_insert_with(|| input_name(&s.id.renamed));
+        }
+        let result = sorted.iter().try_for_each(|&thing| match thing {
+            RustItem::Struct(s) => self
+                .write_input(w, s)
+                .map_err(|e| GenerationError::for_type(e, &s.id.original)),
+            _ => Ok(()),
+        });
+        self.type_mappings = type_mappings;
+        result?;
+
+        self.end_file(w)?;
+
+        Ok(())
+    }
+
+    fn type_map(&mut self) -> &HashMap<String, String> {
+        &self.type_mappings
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "graphql"
+    }
+
+    fn supports(&self, feature: LanguageFeature) -> bool {
+        !matches!(
+            feature,
+            LanguageFeature::Generics | LanguageFeature::AlgebraicEnums
+        )
+    }
+
+    // Types are non-null unless they are wrapped in an `Option`
+    fn format_type(
+        &mut self,
+        ty: &RustType,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        match ty {
+            // GraphQL has no generics, so neither type parameters nor generic types can be used
+            RustType::Generic { id, .. } => {
+                Err(RustTypeFormatError::UnsupportedTypeInGraphQL(id.clone()))
+            }
+            RustType::Simple { id } => {
+                if generic_types.contains(id) {
+                    return Err(RustTypeFormatError::UnsupportedTypeInGraphQL(id.clone()));
+                }
+                Ok(format!("{}!", self.format_simple_type(id, generic_types)?))
+            }
+            RustType::Special(special) => self.format_special_type(special, generic_types),
+        }
+    }
+
+    fn format_special_type(
+        &mut self,
+        special_ty: &SpecialRustType,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        match special_ty {
+            SpecialRustType::Vec(rtype)
+            | SpecialRustType::Array(rtype, _)
+            | SpecialRustType::Slice(rtype)
+            | SpecialRustType::HashSet(rtype) => {
+                Ok(format!("[{}]!", self.format_type(rtype, generic_types)?))
+            }
+            SpecialRustType::Option(rtype) => {
+                let formatted_type = self.format_type(rtype, generic_types)?;
+                Ok(formatted_type
+                    .strip_suffix('!')
+                    .unwrap_or(&formatted_type)
+                    .to_string())
+            }
+            SpecialRustType::String | SpecialRustType::Char => Ok("String!".into()),
+            // GraphQL's `Int` is a signed 32 bit integer
+            SpecialRustType::I8
+            | SpecialRustType::U8
+            | SpecialRustType::I16
+            | SpecialRustType::U16
+            | SpecialRustType::I32 => Ok("Int!".into()),
+            SpecialRustType::U32
+            | SpecialRustType::I54
+            | SpecialRustType::U53
+            | SpecialRustType::F32
+            | SpecialRustType::F64 => Ok("Float!".into()),
+            SpecialRustType::Bool => Ok("Boolean!".into()),
+            SpecialRustType::HashMap(_, _)
+            | SpecialRustType::Unit
+            | SpecialRustType::U64
+            | SpecialRustType::I64
+            | SpecialRustType::ISize
+            | SpecialRustType::USize => Err(RustTypeFormatError::UnsupportedTypeInGraphQL(
+                special_ty.id().to_string(),
+            )),
+        }
+    }
+
+    fn begin_file(&mut self, w: &mut dyn Write) -> io::Result<()> {
+        if !self.no_version_header {
+            writeln!(w, "# Generated by typeshare {}", env!("CARGO_PKG_VERSION"))?;
+            writeln!(w)?;
+        }
+        Ok(())
+    }
+
+    fn write_type_alias(&mut self, w: &mut dyn Write, ty: &RustTypeAlias) -> io::Result<()> {
+        write_description(w, 0, &ty.comments)?;
+        writeln!(w, "scalar {}\n", graphql_name(&ty.id.renamed)?)
+    }
+
+    fn write_struct(&mut self, w: &mut dyn Write, rs: &RustStruct) -> io::Result<()> {
+        write_description(w, 0, &rs.comments)?;
+        // Object types without any fields are not valid SDL
+        if rs.fields.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "struct `{}` has no fields, which GraphQL requires",
+                    rs.id.original
+                ),
+            ));
+        }
+        writeln!(w, "type {} {{", graphql_name(&rs.id.renamed)?)?;
+
+        rs.fields
+            .iter()
+            .try_for_each(|f| self.write_field(w, f, rs.generic_types.as_slice()))?;
+
+        writeln!(w, "}}\n")
+    }
+
+    fn write_enum(&mut self, w: &mut dyn Write, e: &RustEnum) -> io::Result<()> {
+        match e {
+            RustEnum::Unit(shared) => {
+                write_description(w, 0, &shared.comments)?;
+                writeln!(w, "enum {} {{", graphql_name(&shared.id.renamed)?)?;
+
+                for v in &shared.variants {
+                    write_description(w, 1, &v.shared().comments)?;
+                    writeln!(w, "\t{}", graphql_name(&v.shared().id.renamed)?)?;
+                }
+
+                writeln!(w, "}}\n")
+            }
+            RustEnum::Algebraic { shared, .. } => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "algebraic enum `{}` cannot be represented in GraphQL",
+                    shared.id.original
+                ),
+            )),
+        }
+    }
+}
+
+impl GraphQL {
+    fn write_input(&mut self, w: &mut dyn Write, rs: &RustStruct) -> io::Result<()> {
+        write_description(w, 0, &rs.comments)?;
+        writeln!(w, "input {} {{", graphql_name(&input_name(&rs.id.renamed))?)?;
+
+        rs.fields
+            .iter()
+            .try_for_each(|f| self.write_field(w, f, rs.generic_types.as_slice()))?;
+
+        writeln!(w, "}}\n")
+    }
+
+    fn write_field(
+        &mut self,
+        w: &mut dyn Write,
+        field: &RustField,
+        generic_types: &[String],
+    ) -> io::Result<()> {
+        write_description(w, 1, &field.comments)?;
+        let graphql_ty: String = match field.type_override(SupportedLanguage::GraphQL) {
+            Some(type_override) => type_override.to_owned(),
+            None => self
+                .format_type(&field.ty, generic_types)
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?,
+        };
+
+        writeln!(w, "\t{}: {}", graphql_name(&field.id.renamed)?, graphql_ty)
+    }
+}
+
+/// The name of the input type written for the struct named `name`.
+fn input_name(name: &str) -> String {
+    format!("{}Input", name)
+}
+
+/// Checks that `name` is a valid GraphQL name, which may only contain ASCII
+/// letters, digits and underscores, and must not start with a digit.
+fn graphql_name(name: &str) -> io::Result<&str> {
+    let valid = !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if valid {
+        Ok(name)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("`{}` is not a valid GraphQL name", name),
+        ))
+    }
+}
+
+fn write_description(w: &mut dyn Write, indent: usize, comments: &[String]) -> io::Result<()> {
+    if !comments.is_empty() {
+        let tab_indent = "\t".repeat(indent);
+        writeln!(w, "{}\"\"\"", tab_indent)?;
+        for comment in comments {
+            writeln!(w, "{}{}", tab_indent, comment)?;
+        }
+        writeln!(w, "{}\"\"\"", tab_indent)?;
+    }
+    Ok(())
+}
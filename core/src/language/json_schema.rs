@@ -0,0 +1,397 @@
+use crate::parser::ParsedData;
+use crate::rust_types::{RustItem, RustType, RustTypeFormatError, SpecialRustType};
+use crate::{
+    language::{Language, LanguageFeature, SupportedLanguage},
+    rust_types::{RustEnum, RustEnumVariant, RustField, RustStruct, RustTypeAlias},
+    topsort::topsort,
+    GenerationError,
+};
+use std::io;
+use std::{collections::HashMap, io::Write};
+
+/// All information needed to generate a JSON Schema document
+#[derive(Default)]
+pub struct JsonSchema {
+    /// Mappings from Rust type names to JSON Schema fragments,
+    /// such as `{ "type": "string", "format": "date-time" }`
+    pub type_mappings: HashMap<String, String>,
+    /// Whether or not to exclude the version header that normally appears at the top of generated code.
+    /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
+    pub no_version_header: bool,
+}
+
+impl Language for JsonSchema {
+    fn generate_types(
+        &mut self,
+        w: &mut dyn Write,
+        data: &ParsedData,
+    ) -> Result<(), GenerationError> {
+        self.begin_file(w)?;
+
+        let mut items: Vec<RustItem> = vec![];
+
+        for a in &data.aliases {
+            items.push(RustItem::Alias(a.clone()))
+        }
+
+        for s in &data.structs {
+            items.push(RustItem::Struct(s.clone()))
+        }
+
+        for e in &data.enums {
+            items.push(RustItem::Enum(e.clone()))
+        }
+
+        let sorted = topsort(items.iter().collect());
+
+        // Definitions have to be separated by commas, so each one is written out
+        // on its own before they are joined together.
+        let mut definitions = Vec::with_capacity(sorted.len());
+        for &thing in &sorted {
+            let mut definition = Vec::new();
+            let result = match thing {
+                RustItem::Enum(e) => self.write_enum(&mut definition, e),
+                RustItem::Struct(s) => self.write_struct(&mut definition, s),
+                RustItem::Alias(a) => self.write_type_alias(&mut definition, a),
+            };
+            result.map_err(|e| GenerationError::for_type(e, &thing.id().original))?;
+            definitions.push(
+                String::from_utf8(definition)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            );
+        }
+        if !definitions.is_empty() {
+            writeln!(w, "{}", definitions.join(",\n"))?;
+        }
+
+        self.end_file(w)?;
+
+        Ok(())
+    }
+
+    fn type_map(&mut self) -> &HashMap<String, String> {
+        &self.type_mappings
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "json"
+    }
+
+    fn supports(&self, feature: LanguageFeature) -> bool {
+        // Generic parameters are lost: they are written out as accepting any value.
+        !matches!(feature, LanguageFeature::Generics)
+    }
+
+    fn format_simple_type(
+        &mut self,
+        base: &String,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        Ok(if let Some(mapped) = self.type_map().get(base) {
+            mapped.into()
+        } else if generic_types.contains(base) {
+            "{}".into()
+        } else {
+            format!(
+                "{{ \"$ref\": {} }}",
+                json_string(&format!("#/$defs/{}", base))
+            )
+        })
+    }
+
+    fn format_generic_type(
+        &mut self,
+        base: &String,
+        _parameters: &[RustType],
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        // JSON Schema can't express generic parameters, so refer to the generic definition itself.
+        self.format_simple_type(base, generic_types)
+    }
+
+    fn format_special_type(
+        &mut self,
+        special_ty: &SpecialRustType,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        Ok(match special_ty {
+            SpecialRustType::Vec(rtype) | SpecialRustType::Slice(rtype) => format!(
+                "{{ \"type\": \"array\", \"items\": {} }}",
+                self.format_type(rtype, generic_types)?
+            ),
+            SpecialRustType::Array(rtype, len) => format!(
+                "{{ \"type\": \"array\", \"items\": {}, \"minItems\": {len}, \"maxItems\": {len} }}",
+                self.format_type(rtype, generic_types)?,
+                len = len
+            ),
+            SpecialRustType::HashSet(rtype) => format!(
+                "{{ \"type\": \"array\", \"items\": {}, \"uniqueItems\": true }}",
+                self.format_type(rtype, generic_types)?
+            ),
+            // `None` is serialized as `null`
+            SpecialRustType::Option(rtype) => format!(
+                "{{ \"anyOf\": [{}, {{ \"type\": \"null\" }}] }}",
+                self.format_type(rtype, generic_types)?
+            ),
+            // Keys of JSON objects are always strings
+            SpecialRustType::HashMap(_, rtype) => format!(
+                "{{ \"type\": \"object\", \"additionalProperties\": {} }}",
+                self.format_type(rtype, generic_types)?
+            ),
+            SpecialRustType::Unit => "{ \"type\": \"null\" }".into(),
+            SpecialRustType::String => "{ \"type\": \"string\" }".into(),
+            SpecialRustType::Char => {
+                "{ \"type\": \"string\", \"minLength\": 1, \"maxLength\": 1 }".into()
+            }
+            SpecialRustType::I8
+            | SpecialRustType::I16
+            | SpecialRustType::I32
+            | SpecialRustType::I54
+            | SpecialRustType::I64
+            | SpecialRustType::ISize => "{ \"type\": \"integer\" }".into(),
+            SpecialRustType::U8
+            | SpecialRustType::U16
+            | SpecialRustType::U32
+            | SpecialRustType::U53
+            | SpecialRustType::U64
+            | SpecialRustType::USize => "{ \"type\": \"integer\", \"minimum\": 0 }".into(),
+            SpecialRustType::F32 | SpecialRustType::F64 => "{ \"type\": \"number\" }".into(),
+            SpecialRustType::Bool => "{ \"type\": \"boolean\" }".into(),
+        })
+    }
+
+    fn begin_file(&mut self, w: &mut dyn Write) -> io::Result<()> {
+        writeln!(w, "{{")?;
+        if !self.no_version_header {
+            writeln!(
+                w,
+                "\t\"$comment\": \"Generated by typeshare {}\",",
+                env!("CARGO_PKG_VERSION")
+            )?;
+        }
+        writeln!(
+            w,
+            "\t\"$schema\": \"https://json-schema.org/draft/2020-12/schema\","
+        )?;
+        writeln!(w, "\t\"$defs\": {{")
+    }
+
+    fn end_file(&mut self, w: &mut dyn Write) -> io::Result<()> {
+        writeln!(w, "\t}}")?;
+        writeln!(w, "}}")
+    }
+
+    fn write_type_alias(&mut self, w: &mut dyn Write, ty: &RustTypeAlias) -> io::Result<()> {
+        let r#type = self
+            .format_type(&ty.r#type, ty.generic_types.as_slice())
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+
+        if ty.comments.is_empty() {
+            write!(w, "\t\t{}: {}", json_string(&ty.id.original), r#type)
+        } else {
+            let members = vec![
+                description(&ty.comments),
+                format!("\"allOf\": [{}]", r#type),
+            ];
+            write_definition(w, &ty.id.original, &members)
+        }
+    }
+
+    fn write_struct(&mut self, w: &mut dyn Write, rs: &RustStruct) -> io::Result<()> {
+        let mut members = Vec::new();
+        if !rs.comments.is_empty() {
+            members.push(description(&rs.comments));
+        }
+        members.push("\"type\": \"object\"".to_string());
+
+        let (properties, required) = self.format_properties(&rs.fields, &rs.generic_types)?;
+        if properties.is_empty() {
+            members.push("\"properties\": {}".to_string());
+        } else {
+            members.push(format!(
+                "\"properties\": {{\n{}\n\t\t\t}}",
+                properties
+                    .iter()
+                    .map(|property| format!("\t\t\t\t{}", property))
+                    .collect::<Vec<_>>()
+                    .join(",\n")
+            ));
+        }
+        if !required.is_empty() {
+            members.push(format!("\"required\": [{}]", required.join(", ")));
+        }
+
+        write_definition(w, &rs.id.original, &members)
+    }
+
+    fn write_enum(&mut self, w: &mut dyn Write, e: &RustEnum) -> io::Result<()> {
+        let mut members = Vec::new();
+        if !e.shared().comments.is_empty() {
+            members.push(description(&e.shared().comments));
+        }
+
+        match e {
+            RustEnum::Unit(shared) => {
+                members.push("\"type\": \"string\"".to_string());
+                members.push(format!(
+                    "\"enum\": [{}]",
+                    shared
+                        .variants
+                        .iter()
+                        .map(|v| json_string(&v.shared().id.renamed))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+            RustEnum::Algebraic {
+                tag_key,
+                content_key,
+                shared,
+            } => {
+                let variants = shared
+                    .variants
+                    .iter()
+                    .map(|v| self.format_variant(v, tag_key, content_key, &shared.generic_types))
+                    .collect::<io::Result<Vec<_>>>()?;
+
+                if variants.is_empty() {
+                    // An enum without variants can't be constructed, so nothing is valid.
+                    members.push("\"not\": {}".to_string());
+                } else {
+                    members.push(format!(
+                        "\"oneOf\": [\n{}\n\t\t\t]",
+                        variants
+                            .iter()
+                            .map(|variant| format!("\t\t\t\t{}", variant))
+                            .collect::<Vec<_>>()
+                            .join(",\n")
+                    ));
+                }
+            }
+        }
+
+        write_definition(w, &e.shared().id.original, &members)
+    }
+}
+
+impl JsonSchema {
+    /// Formats the variant of an algebraic enum as an object with a constant tag.
+    fn format_variant(
+        &mut self,
+        v: &RustEnumVariant,
+        tag_key: &str,
+        content_key: &str,
+        generic_types: &[String],
+    ) -> io::Result<String> {
+        let tag = format!(
+            "{}: {{ \"const\": {} }}",
+            json_string(tag_key),
+            json_string(&v.shared().id.renamed)
+        );
+
+        let content = match v {
+            RustEnumVariant::Unit(_) => None,
+            RustEnumVariant::Tuple { ty, .. } => {
+                let r#type = self
+                    .format_type(ty, generic_types)
+                    .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+                Some((r#type, !ty.is_optional()))
+            }
+            RustEnumVariant::AnonymousStruct { fields, .. } => {
+                let (properties, required) = self.format_properties(fields, generic_types)?;
+                let mut object = format!(
+                    "{{ \"type\": \"object\", \"properties\": {{ {} }}",
+                    properties.join(", ")
+                );
+                if !required.is_empty() {
+                    object.push_str(&format!(", \"required\": [{}]", required.join(", ")));
+                }
+                object.push_str(" }");
+                Some((object, true))
+            }
+        };
+
+        let mut required = vec![json_string(tag_key)];
+        let properties = match content {
+            Some((content, is_required)) => {
+                if is_required {
+                    required.push(json_string(content_key));
+                }
+                format!("{}, {}: {}", tag, json_string(content_key), content)
+            }
+            None => tag,
+        };
+
+        Ok(format!(
+            "{{ \"type\": \"object\", \"properties\": {{ {} }}, \"required\": [{}] }}",
+            properties,
+            required.join(", ")
+        ))
+    }
+
+    /// Formats each field as a `"name": schema` property, returning the properties
+    /// along with the names of the fields that must be present.
+    fn format_properties(
+        &mut self,
+        fields: &[RustField],
+        generic_types: &[String],
+    ) -> io::Result<(Vec<String>, Vec<String>)> {
+        let mut properties = Vec::with_capacity(fields.len());
+        let mut required = Vec::new();
+
+        for field in fields {
+            let r#type = match field.type_override(SupportedLanguage::JsonSchema) {
+                Some(type_override) => type_override.to_owned(),
+                None => self
+                    .format_type(&field.ty, generic_types)
+                    .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?,
+            };
+
+            let name = json_string(&field.id.renamed);
+            if !(field.ty.is_optional() || field.may_be_absent()) {
+                required.push(name.clone());
+            }
+            properties.push(format!("{}: {}", name, r#type));
+        }
+
+        Ok((properties, required))
+    }
+}
+
+/// Writes a named entry of `$defs` whose value is an object with the given members.
+fn write_definition(w: &mut dyn Write, name: &str, members: &[String]) -> io::Result<()> {
+    writeln!(w, "\t\t{}: {{", json_string(name))?;
+    writeln!(
+        w,
+        "{}",
+        members
+            .iter()
+            .map(|member| format!("\t\t\t{}", member))
+            .collect::<Vec<_>>()
+            .join(",\n")
+    )?;
+    write!(w, "\t\t}}")
+}
+
+fn description(comments: &[String]) -> String {
+    format!("\"description\": {}", json_string(&comments.join("\n")))
+}
+
+/// Formats `s` as a JSON string literal.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -30,6 +30,10 @@ impl Language for Kotlin {
         &self.type_mappings
     }
 
+    fn file_extension(&self) -> &'static str {
+        "kt"
+    }
+
     fn format_special_type(
         &mut self,
         special_ty: &SpecialRustType,
@@ -45,6 +49,9 @@ impl Language for Kotlin {
             SpecialRustType::Slice(rtype) => {
                 format!("List<{}>", self.format_type(rtype, generic_types)?)
             }
+            SpecialRustType::HashSet(rtype) => {
+                format!("Set<{}>", self.format_type(rtype, generic_types)?)
+            }
             SpecialRustType::Option(rtype) => {
                 format!("{}?", self.format_type(rtype, generic_types)?)
             }
@@ -331,7 +338,7 @@ impl Kotlin {
             "\tval {}: {}{}",
             remove_dash_from_identifier(&f.id.renamed),
             ty,
-            (f.has_default && !f.ty.is_optional())
+            (f.may_be_absent() && !f.ty.is_optional())
                 .then(|| "? = null")
                 .or_else(|| f.ty.is_optional().then(|| " = null"))
                 .unwrap_or_default()
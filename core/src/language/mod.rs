@@ -2,41 +2,60 @@ use crate::{
     parser::{ParseError, ParsedData},
     rust_types::{Id, RustEnum, RustEnumVariant, RustItem, RustStruct, RustTypeAlias},
     topsort::topsort,
+    GenerationError,
 };
 use itertools::Itertools;
 use proc_macro2::Ident;
 use std::{collections::HashMap, fmt::Debug, io::Write, str::FromStr};
 
+mod csharp;
 mod go;
+mod graphql;
+mod json_schema;
 mod kotlin;
+mod protobuf;
 mod scala;
 mod swift;
 mod typescript;
+mod zod;
 
 use crate::rust_types::{RustType, RustTypeFormatError, SpecialRustType};
+pub use csharp::CSharp;
 pub use go::Go;
+pub use graphql::GraphQL;
+pub use json_schema::JsonSchema;
 pub use kotlin::Kotlin;
+pub use protobuf::Protobuf;
 pub use scala::Scala;
 pub use swift::GenericConstraints;
 pub use swift::Swift;
 pub use typescript::TypeScript;
+pub use zod::Zod;
 
 /// All supported programming languages.
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum SupportedLanguage {
+    CSharp,
     Go,
+    GraphQL,
+    JsonSchema,
     Kotlin,
+    Protobuf,
     Scala,
     Swift,
     TypeScript,
+    Zod,
 }
 
 impl SupportedLanguage {
     /// Returns an iterator over all supported language variants.
     pub fn all_languages() -> impl Iterator<Item = Self> {
         use SupportedLanguage::*;
-        [Go, Kotlin, Scala, Swift, TypeScript].into_iter()
+        [
+            CSharp, Go, GraphQL, JsonSchema, Kotlin, Protobuf, Scala, Swift, TypeScript, Zod,
+        ]
+        .into_iter()
     }
 }
 
@@ -45,11 +64,16 @@ impl FromStr for SupportedLanguage {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
+            "csharp" => Ok(Self::CSharp),
             "go" => Ok(Self::Go),
+            "graphql" => Ok(Self::GraphQL),
+            "jsonschema" => Ok(Self::JsonSchema),
             "kotlin" => Ok(Self::Kotlin),
+            "protobuf" => Ok(Self::Protobuf),
             "scala" => Ok(Self::Scala),
             "swift" => Ok(Self::Swift),
             "typescript" => Ok(Self::TypeScript),
+            "zod" => Ok(Self::Zod),
             _ => Err(ParseError::UnsupportedLanguage(s.into())),
         }
     }
@@ -63,6 +87,19 @@ impl TryFrom<&Ident> for SupportedLanguage {
     }
 }
 
+/// Constructs a language backend may or may not be able to generate code for.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum LanguageFeature {
+    /// Enums, both unit and algebraic.
+    Enums,
+    /// Enums whose variants carry data.
+    AlgebraicEnums,
+    /// Structs, enums and type aliases with generic parameters.
+    Generics,
+    /// Type aliases, including newtype structs.
+    TypeAliases,
+}
+
 /// Language-specific state and processing.
 ///
 /// The `Language` implementation is allowed to maintain mutable state, and it
@@ -70,12 +107,12 @@ impl TryFrom<&Ident> for SupportedLanguage {
 /// for each `Generator` instance.
 pub trait Language {
     /// Given `data`, generate type-code for this language and write it out to `writable`.
-    /// Returns whether or not writing was successful.
+    /// Returns why generation failed, and for which type, if it was unsuccessful.
     fn generate_types(
         &mut self,
         writable: &mut dyn Write,
         data: &ParsedData,
-    ) -> std::io::Result<()> {
+    ) -> Result<(), GenerationError> {
         self.begin_file(writable)?;
 
         let mut items: Vec<RustItem> = vec![];
@@ -95,11 +132,12 @@ pub trait Language {
         let sorted = topsort(items.iter().collect());
 
         for &thing in &sorted {
-            match thing {
-                RustItem::Enum(e) => self.write_enum(writable, e)?,
-                RustItem::Struct(s) => self.write_struct(writable, s)?,
-                RustItem::Alias(a) => self.write_type_alias(writable, a)?,
-            }
+            let result = match thing {
+                RustItem::Enum(e) => self.write_enum(writable, e),
+                RustItem::Struct(s) => self.write_struct(writable, s),
+                RustItem::Alias(a) => self.write_type_alias(writable, a),
+            };
+            result.map_err(|e| GenerationError::for_type(e, &thing.id().original))?;
         }
 
         self.end_file(writable)?;
@@ -110,6 +148,25 @@ pub trait Language {
     /// Get the type mapping for this language `(Rust type name -> lang type name)`
     fn type_map(&mut self) -> &HashMap<String, String>;
 
+    /// The file extension, without the leading `.`, for files containing
+    /// code generated for this language.
+    fn file_extension(&self) -> &'static str;
+
+    /// Whether this language can generate code for the given construct.
+    fn supports(&self, _feature: LanguageFeature) -> bool {
+        true
+    }
+
+    /// Turn the name of a generated type into a valid identifier for this language.
+    fn mangle_type_name(&self, name: &str) -> String {
+        name.to_string()
+    }
+
+    /// Turn the name of a generated field into a valid identifier for this language.
+    fn mangle_field_name(&self, name: &str) -> String {
+        name.to_string()
+    }
+
     /// Convert a Rust type into a type from this language.
     fn format_type(
         &mut self,
@@ -0,0 +1,201 @@
+use crate::rust_types::{RustTypeFormatError, SpecialRustType};
+use crate::{
+    language::{Language, LanguageFeature, SupportedLanguage},
+    rename::RenameExt,
+    rust_types::{RustEnum, RustField, RustStruct, RustTypeAlias},
+};
+use std::io;
+use std::{collections::HashMap, io::Write};
+
+/// Scalar types that are allowed as the key of a protobuf map.
+const MAP_KEY_TYPES: [&str; 6] = ["string", "bool", "int32", "uint32", "int64", "uint64"];
+
+/// All information needed to generate proto3 definitions
+///
+/// Fields are numbered in declaration order, so reordering or removing fields
+/// changes the wire format of the generated messages.
+#[derive(Default)]
+pub struct Protobuf {
+    /// Name of the protobuf package
+    pub package: String,
+    /// Conversions from Rust type names to protobuf type names.
+    pub type_mappings: HashMap<String, String>,
+    /// Whether or not to exclude the version header that normally appears at the top of generated code.
+    /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
+    pub no_version_header: bool,
+}
+
+impl Language for Protobuf {
+    fn type_map(&mut self) -> &HashMap<String, String> {
+        &self.type_mappings
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "proto"
+    }
+
+    fn supports(&self, feature: LanguageFeature) -> bool {
+        matches!(feature, LanguageFeature::Enums)
+    }
+
+    fn format_special_type(
+        &mut self,
+        special_ty: &SpecialRustType,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        let unsupported = || RustTypeFormatError::UnsupportedTypeInProtobuf(special_ty.id().into());
+
+        Ok(match special_ty {
+            SpecialRustType::Vec(rtype)
+            | SpecialRustType::Array(rtype, _)
+            | SpecialRustType::Slice(rtype)
+            | SpecialRustType::HashSet(rtype) => {
+                let formatted_type = self.format_type(rtype, generic_types)?;
+                // Repeated fields can't hold options or other collections
+                if has_label(&formatted_type) {
+                    return Err(unsupported());
+                }
+                format!("repeated {}", formatted_type)
+            }
+            SpecialRustType::Option(rtype) => {
+                let formatted_type = self.format_type(rtype, generic_types)?;
+                if formatted_type.starts_with("optional ") {
+                    return Err(unsupported());
+                }
+                // Empty collections already take the place of `None`
+                if has_label(&formatted_type) {
+                    formatted_type
+                } else {
+                    format!("optional {}", formatted_type)
+                }
+            }
+            SpecialRustType::HashMap(rtype1, rtype2) => {
+                let key_type = self.format_type(rtype1, generic_types)?;
+                let value_type = self.format_type(rtype2, generic_types)?;
+                if !MAP_KEY_TYPES.contains(&key_type.as_str()) || has_label(&value_type) {
+                    return Err(unsupported());
+                }
+                format!("map<{}, {}>", key_type, value_type)
+            }
+            SpecialRustType::Unit => return Err(unsupported()),
+            SpecialRustType::String | SpecialRustType::Char => "string".into(),
+            // Protobuf has no scalar types smaller than 32 bits
+            SpecialRustType::I8 | SpecialRustType::I16 | SpecialRustType::I32 => "int32".into(),
+            SpecialRustType::U8 | SpecialRustType::U16 | SpecialRustType::U32 => "uint32".into(),
+            SpecialRustType::ISize | SpecialRustType::I54 | SpecialRustType::I64 => "int64".into(),
+            SpecialRustType::USize | SpecialRustType::U53 | SpecialRustType::U64 => "uint64".into(),
+            SpecialRustType::Bool => "bool".into(),
+            SpecialRustType::F32 => "float".into(),
+            SpecialRustType::F64 => "double".into(),
+        })
+    }
+
+    fn begin_file(&mut self, w: &mut dyn Write) -> io::Result<()> {
+        if !self.no_version_header {
+            writeln!(w, "// Generated by typeshare {}", env!("CARGO_PKG_VERSION"))?;
+            writeln!(w)?;
+        }
+        writeln!(w, "syntax = \"proto3\";")?;
+        writeln!(w)?;
+        if !self.package.is_empty() {
+            writeln!(w, "package {};", self.package)?;
+            writeln!(w)?;
+        }
+        Ok(())
+    }
+
+    fn write_type_alias(&mut self, _w: &mut dyn Write, ty: &RustTypeAlias) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "type alias `{}` cannot be represented in Protobuf",
+                ty.id.original
+            ),
+        ))
+    }
+
+    fn write_struct(&mut self, w: &mut dyn Write, rs: &RustStruct) -> io::Result<()> {
+        self.write_comments(w, 0, &rs.comments)?;
+
+        if rs.fields.is_empty() {
+            return writeln!(w, "message {} {{}}\n", rs.id.renamed);
+        }
+
+        writeln!(w, "message {} {{", rs.id.renamed)?;
+
+        for (number, field) in (1..).zip(&rs.fields) {
+            self.write_field(w, field, number, rs.generic_types.as_slice())?;
+        }
+
+        writeln!(w, "}}\n")
+    }
+
+    fn write_enum(&mut self, w: &mut dyn Write, e: &RustEnum) -> io::Result<()> {
+        match e {
+            RustEnum::Unit(shared) => {
+                self.write_comments(w, 0, &shared.comments)?;
+                writeln!(w, "enum {} {{", shared.id.renamed)?;
+
+                // Enum values share the scope of the enum itself, so they are prefixed with its
+                // name to keep variants of different enums from colliding.
+                let prefix = shared.id.original.to_screaming_snake_case();
+                for (number, v) in (0..).zip(&shared.variants) {
+                    self.write_comments(w, 1, &v.shared().comments)?;
+                    writeln!(
+                        w,
+                        "\t{}_{} = {};",
+                        prefix,
+                        v.shared().id.original.to_screaming_snake_case(),
+                        number
+                    )?;
+                }
+
+                writeln!(w, "}}\n")
+            }
+            RustEnum::Algebraic { shared, .. } => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "algebraic enum `{}` cannot be represented in Protobuf",
+                    shared.id.original
+                ),
+            )),
+        }
+    }
+}
+
+impl Protobuf {
+    fn write_field(
+        &mut self,
+        w: &mut dyn Write,
+        field: &RustField,
+        number: usize,
+        generic_types: &[String],
+    ) -> io::Result<()> {
+        self.write_comments(w, 1, &field.comments)?;
+        let ty = match field.type_override(SupportedLanguage::Protobuf) {
+            Some(type_override) => type_override.to_owned(),
+            None => self
+                .format_type(&field.ty, generic_types)
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?,
+        };
+
+        writeln!(w, "\t{} {} = {};", ty, field.id.original, number)
+    }
+
+    fn write_comments(
+        &self,
+        w: &mut dyn Write,
+        indent: usize,
+        comments: &[String],
+    ) -> io::Result<()> {
+        let tab_indent = "\t".repeat(indent);
+        comments
+            .iter()
+            .try_for_each(|comment| writeln!(w, "{}// {}", tab_indent, comment))
+    }
+}
+
+/// Whether a formatted type already carries a label, which protobuf doesn't allow to be nested.
+fn has_label(ty: &str) -> bool {
+    ty.starts_with("repeated ") || ty.starts_with("optional ") || ty.starts_with("map<")
+}
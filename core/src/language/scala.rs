@@ -5,6 +5,7 @@ use crate::rust_types::{RustType, RustTypeFormatError, SpecialRustType};
 use crate::{
     parser::remove_dash_from_identifier,
     rust_types::{RustEnum, RustEnumVariant, RustField, RustStruct, RustTypeAlias},
+    GenerationError,
 };
 use itertools::Itertools;
 use joinery::JoinableIterator;
@@ -31,7 +32,7 @@ impl Language for Scala {
         &mut self,
         writable: &mut dyn Write,
         data: &ParsedData,
-    ) -> std::io::Result<()> {
+    ) -> Result<(), GenerationError> {
         self.begin_file(writable)?;
 
         // Package object to hold type aliases: aliases must be in class or object in Scala 2)
@@ -42,7 +43,8 @@ impl Language for Scala {
                 self.write_unsigned_aliases(writable)?;
             }
             for a in data.aliases.iter() {
-                self.write_type_alias(writable, a)?;
+                self.write_type_alias(writable, a)
+                    .map_err(|e| GenerationError::for_type(e, &a.id.original))?;
             }
             self.end_package_object(writable)?;
         }
@@ -50,10 +52,12 @@ impl Language for Scala {
         if !data.structs.is_empty() || !data.enums.is_empty() {
             self.begin_package(writable)?;
             for s in data.structs.iter() {
-                self.write_struct(writable, s)?;
+                self.write_struct(writable, s)
+                    .map_err(|e| GenerationError::for_type(e, &s.id.original))?;
             }
             for e in data.enums.iter() {
-                self.write_enum(writable, e)?;
+                self.write_enum(writable, e)
+                    .map_err(|err| GenerationError::for_type(err, &e.shared().id.original))?;
             }
             self.end_package(writable)?;
         }
@@ -67,6 +71,10 @@ impl Language for Scala {
         &self.type_mappings
     }
 
+    fn file_extension(&self) -> &'static str {
+        "scala"
+    }
+
     fn format_generic_parameters(&mut self, parameters: Vec<String>) -> String {
         format!("[{}]", parameters.into_iter().join(", "))
     }
@@ -86,6 +94,9 @@ impl Language for Scala {
             SpecialRustType::Slice(rtype) => {
                 format!("Vector[{}]", self.format_type(rtype, generic_types)?)
             }
+            SpecialRustType::HashSet(rtype) => {
+                format!("Set[{}]", self.format_type(rtype, generic_types)?)
+            }
             SpecialRustType::Option(rtype) => {
                 format!("Option[{}]", self.format_type(rtype, generic_types)?)
             }
@@ -123,7 +134,10 @@ impl Language for Scala {
             writeln!(w, " */")?;
         }
         if self.package.is_empty() {
-            panic!("package name must be provided")
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "package name must be provided",
+            ));
         }
         match self.package.rsplit_once('.') {
             None => {}
@@ -361,7 +375,7 @@ impl Scala {
             "\t{}: {}{}",
             remove_dash_from_identifier(&f.id.renamed),
             ty,
-            (f.has_default && !f.ty.is_optional())
+            (f.may_be_absent() && !f.ty.is_optional())
                 .then(|| " = _")
                 .or_else(|| f.ty.is_optional().then(|| " = None"))
                 .unwrap_or_default()
@@ -455,7 +469,11 @@ impl Scala {
             .iter()
             .flat_map(|ty| match ty {
                 RustType::Generic { id: _, parameters } => parameters.clone(),
-                RustType::Special(SpecialRustType::Option(ty) | SpecialRustType::Vec(ty)) => {
+                RustType::Special(
+                    SpecialRustType::Option(ty)
+                    | SpecialRustType::Vec(ty)
+                    | SpecialRustType::HashSet(ty),
+                ) => {
                     vec![ty.deref().clone()]
                 }
                 RustType::Special(SpecialRustType::HashMap(kty, vty)) => {
@@ -153,6 +153,22 @@ impl Language for Swift {
         &self.type_mappings
     }
 
+    fn file_extension(&self) -> &'static str {
+        "swift"
+    }
+
+    fn mangle_type_name(&self, name: &str) -> String {
+        self.mangle_field_name(name)
+    }
+
+    // Keywords can still be used as identifiers once they are escaped with backticks
+    fn mangle_field_name(&self, name: &str) -> String {
+        if SWIFT_KEYWORDS.contains(&name) {
+            return format!("`{}`", name);
+        }
+        name.to_string()
+    }
+
     fn format_simple_type(
         &mut self,
         base: &String,
@@ -180,6 +196,10 @@ impl Language for Swift {
             SpecialRustType::Slice(rtype) => {
                 format!("[{}]", self.format_type(rtype, generic_types)?)
             }
+            // `Set<T>` would require every element type to be `Hashable`
+            SpecialRustType::HashSet(rtype) => {
+                format!("[{}]", self.format_type(rtype, generic_types)?)
+            }
             SpecialRustType::Option(rtype) => {
                 format!("{}?", self.format_type(rtype, generic_types)?)
             }
@@ -247,7 +267,7 @@ impl Language for Swift {
         self.write_comments(w, 0, &ty.comments)?;
 
         let swift_prefix = &self.prefix;
-        let type_name = swift_keyword_aware_rename(&format!("{}{}", swift_prefix, ty.id.renamed));
+        let type_name = self.mangle_type_name(&format!("{}{}", swift_prefix, ty.id.renamed));
 
         writeln!(
             w,
@@ -270,7 +290,7 @@ impl Language for Swift {
         writeln!(w)?;
         self.write_comments(w, 0, &rs.comments)?;
 
-        let type_name = swift_keyword_aware_rename(&format!("{}{}", self.prefix, rs.id.renamed));
+        let type_name = self.mangle_type_name(&format!("{}{}", self.prefix, rs.id.renamed));
 
         // If there are no decorators found for this struct, still write `Codable` and default decorators for structs
         let mut decs = self.get_default_decorators();
@@ -321,7 +341,7 @@ impl Language for Swift {
             if f.id.renamed.chars().any(|c| c == '-') {
                 coding_keys.push(format!(
                     r##"{} = "{}""##,
-                    remove_dash_from_identifier(&swift_keyword_aware_rename(&f.id.renamed)),
+                    remove_dash_from_identifier(&self.mangle_field_name(&f.id.renamed)),
                     &f.id.renamed
                 ));
 
@@ -329,9 +349,9 @@ impl Language for Swift {
                 // situation like this
                 should_write_coding_keys = true;
             } else {
-                coding_keys.push(remove_dash_from_identifier(&swift_keyword_aware_rename(
-                    &f.id.renamed,
-                )));
+                coding_keys.push(remove_dash_from_identifier(
+                    &self.mangle_field_name(&f.id.renamed),
+                ));
             }
 
             let case_type: String = match f.type_override(SupportedLanguage::Swift) {
@@ -344,9 +364,9 @@ impl Language for Swift {
             writeln!(
                 w,
                 "\tpublic let {}: {}{}",
-                remove_dash_from_identifier(&swift_keyword_aware_rename(&f.id.renamed)),
+                remove_dash_from_identifier(&self.mangle_field_name(&f.id.renamed)),
                 case_type,
-                (f.has_default && !f.ty.is_optional())
+                (f.may_be_absent() && !f.ty.is_optional())
                     .then(|| "?")
                     .unwrap_or_default()
             )?;
@@ -380,7 +400,7 @@ impl Language for Swift {
                 "{}: {}{}",
                 remove_dash_from_identifier(&f.id.renamed),
                 swift_ty,
-                (f.has_default && !f.ty.is_optional())
+                (f.may_be_absent() && !f.ty.is_optional())
                     .then(|| "?")
                     .unwrap_or_default()
             ));
@@ -392,7 +412,7 @@ impl Language for Swift {
                 w,
                 "\n\t\tself.{} = {}",
                 remove_dash_from_identifier(&f.id.renamed),
-                remove_dash_from_identifier(&swift_keyword_aware_rename(&f.id.renamed))
+                remove_dash_from_identifier(&self.mangle_field_name(&f.id.renamed))
             )?;
         }
         if !rs.fields.is_empty() {
@@ -433,8 +453,7 @@ impl Language for Swift {
         }
 
         let shared = e.shared();
-        let enum_name =
-            swift_keyword_aware_rename(&format!("{}{}", self.prefix, shared.id.renamed));
+        let enum_name = self.mangle_type_name(&format!("{}{}", self.prefix, shared.id.renamed));
         let always_present = match e {
             RustEnum::Unit(_) => {
                 let mut always_present = vec!["String".into()];
@@ -554,13 +573,13 @@ impl Swift {
                     self.write_comments(w, 1, &v.shared().comments)?;
                     if v.shared().id.renamed == variant_name {
                         // We don't need to handle any renaming
-                        writeln!(w, "\tcase {}", &swift_keyword_aware_rename(&variant_name))?;
+                        writeln!(w, "\tcase {}", &self.mangle_field_name(&variant_name))?;
                     } else {
                         // We do need to handle renaming
                         writeln!(
                             w,
                             "\tcase {} = {:?}",
-                            swift_keyword_aware_rename(&variant_name),
+                            self.mangle_field_name(&variant_name),
                             &v.shared().id.renamed
                         )?;
                     }
@@ -593,16 +612,16 @@ impl Swift {
                     };
 
                     coding_keys.push(if variant_name == v.shared().id.renamed {
-                        swift_keyword_aware_rename(&variant_name)
+                        self.mangle_field_name(&variant_name)
                     } else {
                         format!(
                             r##"{} = "{}""##,
-                            swift_keyword_aware_rename(&variant_name),
+                            self.mangle_field_name(&variant_name),
                             &v.shared().id.renamed
                         )
                     });
 
-                    write!(w, "\tcase {}", swift_keyword_aware_rename(&variant_name))?;
+                    write!(w, "\tcase {}", self.mangle_field_name(&variant_name))?;
 
                     match v {
                         RustEnumVariant::Unit(_) => {
@@ -619,7 +638,7 @@ impl Swift {
 		case .{case_name}:
 			try container.encode(CodingKeys.{case_name}, forKey: .{tag_key})",
                                 tag_key = tag_key,
-                                case_name = swift_keyword_aware_rename(&variant_name),
+                                case_name = self.mangle_field_name(&variant_name),
                             ));
                         }
                         RustEnumVariant::Tuple { ty, .. } => {
@@ -627,7 +646,7 @@ impl Swift {
                             let case_type = self
                                 .format_type(ty, e.shared().generic_types.as_slice())
                                 .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
-                            write!(w, "({})", swift_keyword_aware_rename(&case_type))?;
+                            write!(w, "({})", self.mangle_type_name(&case_type))?;
 
                             if content_optional {
                                 decoding_cases.push(format!(
@@ -642,7 +661,7 @@ impl Swift {
 					return
 				}}",
                                     content_key = content_key,
-                                    case_type = swift_keyword_aware_rename(&case_type),
+                                    case_type = self.mangle_type_name(&case_type),
                                     case_name = &variant_name
                                 ))
                             } else {
@@ -654,7 +673,7 @@ impl Swift {
 					return
 				}}",
                                     content_key = content_key,
-                                    case_type = swift_keyword_aware_rename(&case_type),
+                                    case_type = self.mangle_type_name(&case_type),
                                     case_name = &variant_name,
                                 ));
                             }
@@ -756,10 +775,3 @@ impl Swift {
         decs
     }
 }
-
-fn swift_keyword_aware_rename(name: &str) -> String {
-    if SWIFT_KEYWORDS.contains(&name) {
-        return format!("`{}`", name);
-    }
-    name.to_string()
-}
@@ -23,6 +23,14 @@ impl Language for TypeScript {
         &self.type_mappings
     }
 
+    fn file_extension(&self) -> &'static str {
+        "ts"
+    }
+
+    fn mangle_field_name(&self, name: &str) -> String {
+        quote_property_name(name)
+    }
+
     fn format_special_type(
         &mut self,
         special_ty: &SpecialRustType,
@@ -44,6 +52,10 @@ impl Language for TypeScript {
             SpecialRustType::Slice(rtype) => {
                 Ok(format!("{}[]", self.format_type(rtype, generic_types)?))
             }
+            // Sets are serialized as JSON arrays, which `Set<T>` cannot be parsed from
+            SpecialRustType::HashSet(rtype) => {
+                Ok(format!("{}[]", self.format_type(rtype, generic_types)?))
+            }
             // We add optionality above the type formatting level
             SpecialRustType::Option(rtype) => self.format_type(rtype, generic_types),
             SpecialRustType::HashMap(rtype1, rtype2) => Ok(format!(
@@ -73,9 +85,9 @@ impl Language for TypeScript {
             SpecialRustType::U64
             | SpecialRustType::I64
             | SpecialRustType::ISize
-            | SpecialRustType::USize => {
-                panic!("64 bit types not allowed in Typeshare")
-            }
+            | SpecialRustType::USize => Err(RustTypeFormatError::UnsupportedTypeInTS(
+                special_ty.id().to_string(),
+            )),
         }
     }
 
@@ -90,7 +102,7 @@ impl Language for TypeScript {
     }
 
     fn write_type_alias(&mut self, w: &mut dyn Write, ty: &RustTypeAlias) -> io::Result<()> {
-        self.write_comments(w, 0, &ty.comments)?;
+        write_comments(w, 0, &ty.comments)?;
 
         let r#type = self
             .format_type(&ty.r#type, ty.generic_types.as_slice())
@@ -99,7 +111,7 @@ impl Language for TypeScript {
         writeln!(
             w,
             "export type {}{} = {}{};\n",
-            ty.id.renamed,
+            self.mangle_type_name(&ty.id.renamed),
             (!ty.generic_types.is_empty())
                 .then(|| format!("<{}>", ty.generic_types.join(", ")))
                 .unwrap_or_default(),
@@ -114,11 +126,11 @@ impl Language for TypeScript {
     }
 
     fn write_struct(&mut self, w: &mut dyn Write, rs: &RustStruct) -> io::Result<()> {
-        self.write_comments(w, 0, &rs.comments)?;
+        write_comments(w, 0, &rs.comments)?;
         writeln!(
             w,
             "export interface {}{} {{",
-            rs.id.renamed,
+            self.mangle_type_name(&rs.id.renamed),
             (!rs.generic_types.is_empty())
                 .then(|| format!("<{}>", rs.generic_types.join(", ")))
                 .unwrap_or_default()
@@ -132,7 +144,7 @@ impl Language for TypeScript {
     }
 
     fn write_enum(&mut self, w: &mut dyn Write, e: &RustEnum) -> io::Result<()> {
-        self.write_comments(w, 0, &e.shared().comments)?;
+        write_comments(w, 0, &e.shared().comments)?;
 
         let generic_parameters = (!e.shared().generic_types.is_empty())
             .then(|| format!("<{}>", e.shared().generic_types.join(", ")))
@@ -143,7 +155,8 @@ impl Language for TypeScript {
                 write!(
                     w,
                     "export enum {}{} {{",
-                    shared.id.renamed, generic_parameters
+                    self.mangle_type_name(&shared.id.renamed),
+                    generic_parameters
                 )?;
 
                 self.write_enum_variants(w, e)?;
@@ -154,7 +167,8 @@ impl Language for TypeScript {
                 write!(
                     w,
                     "export type {}{} = ",
-                    shared.id.renamed, generic_parameters
+                    self.mangle_type_name(&shared.id.renamed),
+                    generic_parameters
                 )?;
 
                 self.write_enum_variants(w, e)?;
@@ -175,7 +189,7 @@ impl TypeScript {
             RustEnum::Unit(shared) => shared.variants.iter().try_for_each(|v| match v {
                 RustEnumVariant::Unit(shared) => {
                     writeln!(w)?;
-                    self.write_comments(w, 1, &shared.comments)?;
+                    write_comments(w, 1, &shared.comments)?;
                     write!(w, "\t{} = {:?},", shared.id.original, &shared.id.renamed)
                 }
                 _ => unreachable!(),
@@ -189,7 +203,7 @@ impl TypeScript {
                 shared,
             } => shared.variants.iter().try_for_each(|v| {
                 writeln!(w)?;
-                self.write_comments(w, 1, &v.shared().comments)?;
+                write_comments(w, 1, &v.shared().comments)?;
                 match v {
                     RustEnumVariant::Unit(shared) => write!(
                         w,
@@ -235,7 +249,7 @@ impl TypeScript {
         field: &RustField,
         generic_types: &[String],
     ) -> io::Result<()> {
-        self.write_comments(w, 1, &field.comments)?;
+        write_comments(w, 1, &field.comments)?;
         let ts_ty: String = match field.type_override(SupportedLanguage::TypeScript) {
             Some(type_override) => type_override.to_owned(),
             None => self
@@ -243,7 +257,7 @@ impl TypeScript {
                 .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?,
         };
 
-        let optional = field.ty.is_optional() || field.has_default;
+        let optional = field.ty.is_optional() || field.may_be_absent();
         let double_optional = field.ty.is_double_optional();
         let is_readonly = field
             .decorators
@@ -254,7 +268,7 @@ impl TypeScript {
             w,
             "\t{}{}{}: {}{};",
             is_readonly.then(|| "readonly ").unwrap_or_default(),
-            typescript_property_aware_rename(&field.id.renamed),
+            self.mangle_field_name(&field.id.renamed),
             optional.then(|| "?").unwrap_or_default(),
             ts_ty,
             double_optional.then(|| " | null").unwrap_or_default()
@@ -262,40 +276,45 @@ impl TypeScript {
 
         Ok(())
     }
-
-    fn write_comments(
-        &mut self,
-        w: &mut dyn Write,
-        indent: usize,
-        comments: &[String],
-    ) -> io::Result<()> {
-        // Only attempt to write a comment if there are some, otherwise we're Ok()
-        if !comments.is_empty() {
-            let comment: String = {
-                let tab_indent = "\t".repeat(indent);
-                // If there's only one comment then keep it on the same line, otherwise we'll make a nice multi-line comment
-                if comments.len() == 1 {
-                    format!("{}/** {} */", tab_indent, comments.first().unwrap())
-                } else {
-                    let joined_comments = comments.join(&format!("\n{} * ", tab_indent));
-                    format!(
-                        "{tab}/**
-{tab} * {comment}
-{tab} */",
-                        tab = tab_indent,
-                        comment = joined_comments
-                    )
-                }
-            };
-            writeln!(w, "{}", comment)?;
-        }
-        Ok(())
-    }
 }
 
-fn typescript_property_aware_rename(name: &str) -> String {
+/// Properties that aren't valid identifiers, such as renamed kebab-case properties, need to be quoted.
+///
+/// Shared with the Zod backend, whose schemas use the same property syntax.
+pub(super) fn quote_property_name(name: &str) -> String {
     if name.chars().any(|c| c == '-') {
         return format!("{:?}", name);
     }
     name.to_string()
 }
+
+/// Writes `comments` as a JSDoc comment.
+///
+/// Shared with the Zod backend, whose output is also Typescript.
+pub(super) fn write_comments(
+    w: &mut dyn Write,
+    indent: usize,
+    comments: &[String],
+) -> io::Result<()> {
+    // Only attempt to write a comment if there are some, otherwise we're Ok()
+    if !comments.is_empty() {
+        let comment: String = {
+            let tab_indent = "\t".repeat(indent);
+            // If there's only one comment then keep it on the same line, otherwise we'll make a nice multi-line comment
+            if comments.len() == 1 {
+                format!("{}/** {} */", tab_indent, comments.first().unwrap())
+            } else {
+                let joined_comments = comments.join(&format!("\n{} * ", tab_indent));
+                format!(
+                    "{tab}/**
+{tab} * {comment}
+{tab} */",
+                    tab = tab_indent,
+                    comment = joined_comments
+                )
+            }
+        };
+        writeln!(w, "{}", comment)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,594 @@
+use joinery::JoinableIterator;
+
+use crate::parser::ParsedData;
+use crate::rust_types::{RustItem, RustType, RustTypeFormatError, SpecialRustType};
+use crate::{
+    language::{
+        typescript::{quote_property_name, write_comments},
+        Language, LanguageFeature, SupportedLanguage,
+    },
+    rust_types::{RustEnum, RustEnumVariant, RustField, RustStruct, RustTypeAlias},
+    topsort::topsort,
+    GenerationError,
+};
+use std::io;
+use std::{
+    collections::{HashMap, HashSet},
+    io::Write,
+};
+
+/// All information needed to generate Zod schemas for Typescript
+///
+/// Schemas are declared after the schemas they refer to. Types that refer to each other can't
+/// all be ordered that way, so references to schemas that are declared later are wrapped in
+/// `z.lazy`. Typescript can't infer the type of a schema that refers to itself, so schemas with
+/// such references have their type written out explicitly instead.
+#[derive(Default)]
+pub struct Zod {
+    /// Mappings from Rust type names to Zod schemas
+    pub type_mappings: HashMap<String, String>,
+    /// Whether or not to exclude the version header that normally appears at the top of generated code.
+    /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
+    pub no_version_header: bool,
+}
+
+impl Language for Zod {
+    fn generate_types(
+        &mut self,
+        w: &mut dyn Write,
+        data: &ParsedData,
+    ) -> Result<(), GenerationError> {
+        let mut items: Vec<RustItem> = vec![];
+
+        for a in &data.aliases {
+            items.push(RustItem::Alias(a.clone()))
+        }
+
+        for s in &data.structs {
+            items.push(RustItem::Struct(s.clone()))
+        }
+
+        for e in &data.enums {
+            items.push(RustItem::Enum(e.clone()))
+        }
+
+        let sorted = topsort(items.iter().collect());
+
+        // References to schemas that aren't declared yet are only added to the type mappings
+        // while this file is generated
+        let type_mappings = self.type_mappings.clone();
+        let result = self.write_items(w, &sorted, &type_mappings);
+        self.type_mappings = type_mappings;
+        result
+    }
+
+    fn type_map(&mut self) -> &HashMap<String, String> {
+        &self.type_mappings
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "ts"
+    }
+
+    fn mangle_field_name(&self, name: &str) -> String {
+        quote_property_name(name)
+    }
+
+    fn supports(&self, feature: LanguageFeature) -> bool {
+        // Generic parameters are lost: they are written out as accepting any value.
+        !matches!(feature, LanguageFeature::Generics)
+    }
+
+    fn format_simple_type(
+        &mut self,
+        base: &String,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        Ok(if let Some(mapped) = self.type_map().get(base) {
+            mapped.into()
+        } else if generic_types.contains(base) {
+            "z.unknown()".into()
+        } else {
+            base.into()
+        })
+    }
+
+    fn format_generic_type(
+        &mut self,
+        base: &String,
+        _parameters: &[RustType],
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        // Zod schemas can't take generic parameters, so refer to the generic schema itself.
+        self.format_simple_type(base, generic_types)
+    }
+
+    fn format_special_type(
+        &mut self,
+        special_ty: &SpecialRustType,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        match special_ty {
+            SpecialRustType::Vec(rtype) => Ok(format!(
+                "z.array({})",
+                self.format_type(rtype, generic_types)?
+            )),
+            SpecialRustType::Array(rtype, len) => {
+                let formatted_type = self.format_type(rtype, generic_types)?;
+                Ok(format!(
+                    "z.tuple([{}])",
+                    std::iter::repeat(&formatted_type)
+                        .take(*len)
+                        .join_with(", ")
+                ))
+            }
+            SpecialRustType::Slice(rtype) => Ok(format!(
+                "z.array({})",
+                self.format_type(rtype, generic_types)?
+            )),
+            // Sets are serialized as JSON arrays
+            SpecialRustType::HashSet(rtype) => Ok(format!(
+                "z.array({})",
+                self.format_type(rtype, generic_types)?
+            )),
+            // `None` is serialized as `null`; missing fields are handled when writing fields
+            SpecialRustType::Option(rtype) => Ok(format!(
+                "{}.nullable()",
+                self.format_type(rtype, generic_types)?
+            )),
+            SpecialRustType::HashMap(rtype1, rtype2) => {
+                let key_type = match rtype1.as_ref() {
+                    RustType::Simple { id } if generic_types.contains(id) => {
+                        return Err(RustTypeFormatError::GenericKeyForbiddenInTS(id.clone()));
+                    }
+                    _ => self.format_type(rtype1, generic_types)?,
+                };
+                // JSON object keys are always strings, so numeric keys have to be converted
+                let key_type = match key_type.strip_prefix("z.number()") {
+                    Some(checks) => format!("z.coerce.number(){}", checks),
+                    None => key_type,
+                };
+                Ok(format!(
+                    "z.record({}, {})",
+                    key_type,
+                    self.format_type(rtype2, generic_types)?
+                ))
+            }
+            SpecialRustType::Unit => Ok("z.null()".into()),
+            SpecialRustType::String => Ok("z.string()".into()),
+            SpecialRustType::Char => Ok("z.string().length(1)".into()),
+            SpecialRustType::I8
+            | SpecialRustType::I16
+            | SpecialRustType::I32
+            | SpecialRustType::I54 => Ok("z.number().int()".into()),
+            SpecialRustType::U8
+            | SpecialRustType::U16
+            | SpecialRustType::U32
+            | SpecialRustType::U53 => Ok("z.number().int().nonnegative()".into()),
+            SpecialRustType::F32 | SpecialRustType::F64 => Ok("z.number()".into()),
+            SpecialRustType::Bool => Ok("z.boolean()".into()),
+            SpecialRustType::U64
+            | SpecialRustType::I64
+            | SpecialRustType::ISize
+            | SpecialRustType::USize => Err(RustTypeFormatError::UnsupportedTypeInTS(
+                special_ty.id().to_string(),
+            )),
+        }
+    }
+
+    fn begin_file(&mut self, w: &mut dyn Write) -> io::Result<()> {
+        if !self.no_version_header {
+            writeln!(w, "/*")?;
+            writeln!(w, " Generated by typeshare {}", env!("CARGO_PKG_VERSION"))?;
+            writeln!(w, "*/")?;
+            writeln!(w)?;
+        }
+        writeln!(w, "import {{ z }} from \"zod\";")?;
+        writeln!(w)
+    }
+
+    fn write_type_alias(&mut self, w: &mut dyn Write, ty: &RustTypeAlias) -> io::Result<()> {
+        self.write_alias_schema(w, ty, None)
+    }
+
+    fn write_struct(&mut self, w: &mut dyn Write, rs: &RustStruct) -> io::Result<()> {
+        self.write_struct_schema(w, rs, None)
+    }
+
+    fn write_enum(&mut self, w: &mut dyn Write, e: &RustEnum) -> io::Result<()> {
+        self.write_enum_schema(w, e, None)
+    }
+}
+
+impl Zod {
+    fn write_items(
+        &mut self,
+        w: &mut dyn Write,
+        items: &[&RustItem],
+        type_mappings: &HashMap<String, String>,
+    ) -> Result<(), GenerationError> {
+        let mut undeclared = HashSet::new();
+        for &item in items {
+            let name = &item.id().original;
+            if !self.type_mappings.contains_key(name) {
+                self.type_mappings
+                    .insert(name.clone(), format!("z.lazy(() => {})", name));
+                undeclared.insert(name.clone());
+            }
+        }
+
+        self.begin_file(w)?;
+
+        for &item in items {
+            let is_recursive = item_types(item)
+                .iter()
+                .any(|ty| undeclared.iter().any(|name| ty.contains_type(name)));
+            let name = &item.id().original;
+            let explicit_type = if is_recursive {
+                Some(explicit_type(item, type_mappings).map_err(|source| {
+                    GenerationError::UnmappableType {
+                        type_name: name.clone(),
+                        source,
+                    }
+                })?)
+            } else {
+                None
+            };
+
+            let result = match item {
+                RustItem::Enum(e) => self.write_enum_schema(w, e, explicit_type),
+                RustItem::Struct(s) => self.write_struct_schema(w, s, explicit_type),
+                RustItem::Alias(a) => self.write_alias_schema(w, a, explicit_type),
+            };
+            result.map_err(|e| GenerationError::for_type(e, name))?;
+
+            if undeclared.remove(name) {
+                self.type_mappings.remove(name);
+            }
+        }
+
+        self.end_file(w)?;
+
+        Ok(())
+    }
+
+    fn write_alias_schema(
+        &mut self,
+        w: &mut dyn Write,
+        ty: &RustTypeAlias,
+        explicit_type: Option<String>,
+    ) -> io::Result<()> {
+        write_comments(w, 0, &ty.comments)?;
+
+        let r#type = self
+            .format_type(&ty.r#type, ty.generic_types.as_slice())
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+
+        write_declaration(w, &ty.id.renamed, explicit_type.is_some())?;
+        writeln!(w, " = {};", r#type)?;
+        write_type(w, &ty.id.renamed, explicit_type)
+    }
+
+    fn write_struct_schema(
+        &mut self,
+        w: &mut dyn Write,
+        rs: &RustStruct,
+        explicit_type: Option<String>,
+    ) -> io::Result<()> {
+        write_comments(w, 0, &rs.comments)?;
+
+        write_declaration(w, &rs.id.renamed, explicit_type.is_some())?;
+        if rs.fields.is_empty() {
+            writeln!(w, " = z.object({{}});")?;
+        } else {
+            writeln!(w, " = z.object({{")?;
+
+            rs.fields
+                .iter()
+                .try_for_each(|f| self.write_field(w, f, rs.generic_types.as_slice()))?;
+
+            writeln!(w, "}});")?;
+        }
+        write_type(w, &rs.id.renamed, explicit_type)
+    }
+
+    fn write_enum_schema(
+        &mut self,
+        w: &mut dyn Write,
+        e: &RustEnum,
+        explicit_type: Option<String>,
+    ) -> io::Result<()> {
+        write_comments(w, 0, &e.shared().comments)?;
+
+        let shared = e.shared();
+        write_declaration(w, &shared.id.renamed, explicit_type.is_some())?;
+        if shared.variants.is_empty() {
+            // An enum without variants can't be constructed
+            writeln!(w, " = z.never();")?;
+            return write_type(w, &shared.id.renamed, explicit_type);
+        }
+
+        match e {
+            RustEnum::Unit(shared) => writeln!(
+                w,
+                " = z.enum([{}]);",
+                shared
+                    .variants
+                    .iter()
+                    .map(|v| format!("{:?}", v.shared().id.renamed))
+                    .join_with(", ")
+            )?,
+            RustEnum::Algebraic {
+                tag_key,
+                content_key,
+                shared,
+            } => {
+                writeln!(w, " = z.discriminatedUnion({:?}, [", tag_key)?;
+
+                shared.variants.iter().try_for_each(|v| {
+                    write_comments(w, 1, &v.shared().comments)?;
+                    let tag = format!(
+                        "{}: z.literal({:?})",
+                        self.mangle_field_name(tag_key),
+                        v.shared().id.renamed
+                    );
+                    let content = match v {
+                        RustEnumVariant::Unit(_) => None,
+                        RustEnumVariant::Tuple { ty, .. } => {
+                            let r#type = self
+                                .format_type(ty, shared.generic_types.as_slice())
+                                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+                            Some(if ty.is_optional() {
+                                format!("{}.optional()", r#type)
+                            } else {
+                                r#type
+                            })
+                        }
+                        RustEnumVariant::AnonymousStruct { fields, .. } => {
+                            let fields = fields
+                                .iter()
+                                .map(|f| self.format_field(f, shared.generic_types.as_slice()))
+                                .collect::<io::Result<Vec<_>>>()?;
+                            Some(format!("z.object({{ {} }})", fields.join(", ")))
+                        }
+                    };
+
+                    match content {
+                        Some(content) => writeln!(
+                            w,
+                            "\tz.object({{ {}, {}: {} }}),",
+                            tag,
+                            self.mangle_field_name(content_key),
+                            content
+                        ),
+                        None => writeln!(w, "\tz.object({{ {} }}),", tag),
+                    }
+                })?;
+
+                writeln!(w, "]);")?
+            }
+        }
+        write_type(w, &shared.id.renamed, explicit_type)
+    }
+
+    fn write_field(
+        &mut self,
+        w: &mut dyn Write,
+        field: &RustField,
+        generic_types: &[String],
+    ) -> io::Result<()> {
+        write_comments(w, 1, &field.comments)?;
+        writeln!(w, "\t{},", self.format_field(field, generic_types)?)
+    }
+
+    /// Formats a field as a `name: schema` property of a `z.object`.
+    fn format_field(&mut self, field: &RustField, generic_types: &[String]) -> io::Result<String> {
+        let zod_ty: String = match field.type_override(SupportedLanguage::Zod) {
+            Some(type_override) => type_override.to_owned(),
+            None => self
+                .format_type(&field.ty, generic_types)
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?,
+        };
+
+        let optional = field.ty.is_optional() || field.may_be_absent();
+        Ok(format!(
+            "{}: {}{}",
+            self.mangle_field_name(&field.id.renamed),
+            zod_ty,
+            optional.then(|| ".optional()").unwrap_or_default()
+        ))
+    }
+}
+
+/// Starts the declaration of the schema called `name`, annotating it with its Typescript type if
+/// that type is written out explicitly.
+fn write_declaration(w: &mut dyn Write, name: &str, explicit_type: Option<&str>) -> io::Result<()> {
+    match explicit_type {
+        Some(_) => write!(w, "export const {name}: z.ZodType<{name}>", name = name),
+        None => write!(w, "export const {}", name),
+    }
+}
+
+/// Writes the Typescript type of the schema called `name`, which is inferred from the schema
+/// unless it is given explicitly.
+fn write_type(w: &mut dyn Write, name: &str, explicit_type: Option<String>) -> io::Result<()> {
+    match explicit_type {
+        Some(r#type) => writeln!(w, "export type {} = {};\n", name, r#type),
+        None => writeln!(
+            w,
+            "export type {name} = z.infer<typeof {name}>;\n",
+            name = name
+        ),
+    }
+}
+
+/// The Typescript type matching the schema of `item`.
+///
+/// Types without a schema of their own, such as mapped types and generic parameters, are `unknown`.
+fn explicit_type(
+    item: &RustItem,
+    type_mappings: &HashMap<String, String>,
+) -> Result<String, RustTypeFormatError> {
+    match item {
+        RustItem::Alias(a) => ts_type(&a.r#type, &a.generic_types, type_mappings),
+        RustItem::Struct(s) => ts_object_type(&s.fields, &s.generic_types, type_mappings),
+        RustItem::Enum(RustEnum::Unit(shared)) => Ok(shared
+            .variants
+            .iter()
+            .map(|v| format!("{:?}", v.shared().id.renamed))
+            .join_with(" | ")
+            .to_string()),
+        RustItem::Enum(RustEnum::Algebraic {
+            tag_key,
+            content_key,
+            shared,
+        }) => {
+            let variants = shared
+                .variants
+                .iter()
+                .map(|v| {
+                    let tag = format!(
+                        "{}: {:?}",
+                        quote_property_name(tag_key),
+                        v.shared().id.renamed
+                    );
+                    let content = match v {
+                        RustEnumVariant::Unit(_) => return Ok(format!("{{ {} }}", tag)),
+                        RustEnumVariant::Tuple { ty, .. } => format!(
+                            "{}{}: {}",
+                            quote_property_name(content_key),
+                            ty.is_optional().then(|| "?").unwrap_or_default(),
+                            ts_type(ty, &shared.generic_types, type_mappings)?
+                        ),
+                        RustEnumVariant::AnonymousStruct { fields, .. } => format!(
+                            "{}: {}",
+                            quote_property_name(content_key),
+                            ts_object_type(fields, &shared.generic_types, type_mappings)?
+                        ),
+                    };
+                    Ok(format!("{{ {}; {} }}", tag, content))
+                })
+                .collect::<Result<Vec<_>, RustTypeFormatError>>()?;
+            Ok(if variants.is_empty() {
+                "never".to_string()
+            } else {
+                variants.join(" | ")
+            })
+        }
+    }
+}
+
+fn ts_object_type(
+    fields: &[RustField],
+    generic_types: &[String],
+    type_mappings: &HashMap<String, String>,
+) -> Result<String, RustTypeFormatError> {
+    let fields = fields
+        .iter()
+        .map(|f| {
+            let r#type = match f.type_override(SupportedLanguage::Zod) {
+                Some(_) => "unknown".to_string(),
+                None => ts_type(&f.ty, generic_types, type_mappings)?,
+            };
+            let optional = f.ty.is_optional() || f.may_be_absent();
+            Ok(format!(
+                "{}{}: {}",
+                quote_property_name(&f.id.renamed),
+                optional.then(|| "?").unwrap_or_default(),
+                r#type
+            ))
+        })
+        .collect::<Result<Vec<_>, RustTypeFormatError>>()?;
+    Ok(if fields.is_empty() {
+        "{}".to_string()
+    } else {
+        format!("{{ {} }}", fields.join("; "))
+    })
+}
+
+fn ts_type(
+    ty: &RustType,
+    generic_types: &[String],
+    type_mappings: &HashMap<String, String>,
+) -> Result<String, RustTypeFormatError> {
+    let element = |ty: &RustType| {
+        ts_type(ty, generic_types, type_mappings).map(|r#type| {
+            if r#type.contains(' ') {
+                format!("({})", r#type)
+            } else {
+                r#type
+            }
+        })
+    };
+
+    Ok(match ty {
+        RustType::Simple { id } | RustType::Generic { id, .. } => {
+            if generic_types.contains(id) || type_mappings.contains_key(id) {
+                "unknown".to_string()
+            } else {
+                id.clone()
+            }
+        }
+        RustType::Special(special_ty) => match special_ty {
+            SpecialRustType::Vec(rtype)
+            | SpecialRustType::Slice(rtype)
+            | SpecialRustType::HashSet(rtype) => format!("{}[]", element(rtype)?),
+            SpecialRustType::Array(rtype, len) => {
+                let r#type = ts_type(rtype, generic_types, type_mappings)?;
+                format!(
+                    "[{}]",
+                    std::iter::repeat(&r#type).take(*len).join_with(", ")
+                )
+            }
+            SpecialRustType::Option(rtype) => {
+                format!("{} | null", ts_type(rtype, generic_types, type_mappings)?)
+            }
+            SpecialRustType::HashMap(rtype1, rtype2) => format!(
+                "Record<{}, {}>",
+                ts_type(rtype1, generic_types, type_mappings)?,
+                ts_type(rtype2, generic_types, type_mappings)?
+            ),
+            SpecialRustType::Unit => "null".to_string(),
+            SpecialRustType::String | SpecialRustType::Char => "string".to_string(),
+            SpecialRustType::I8
+            | SpecialRustType::U8
+            | SpecialRustType::I16
+            | SpecialRustType::U16
+            | SpecialRustType::I32
+            | SpecialRustType::U32
+            | SpecialRustType::I54
+            | SpecialRustType::U53
+            | SpecialRustType::F32
+            | SpecialRustType::F64 => "number".to_string(),
+            SpecialRustType::Bool => "boolean".to_string(),
+            SpecialRustType::U64
+            | SpecialRustType::I64
+            | SpecialRustType::ISize
+            | SpecialRustType::USize => {
+                return Err(RustTypeFormatError::UnsupportedTypeInTS(
+                    special_ty.id().to_string(),
+                ))
+            }
+        },
+    })
+}
+
+/// Every type used by `item`.
+fn item_types(item: &RustItem) -> Vec<&RustType> {
+    match item {
+        RustItem::Alias(a) => vec![&a.r#type],
+        RustItem::Struct(s) => s.fields.iter().map(|f| &f.ty).collect(),
+        RustItem::Enum(e) => e
+            .shared()
+            .variants
+            .iter()
+            .flat_map(|v| match v {
+                RustEnumVariant::Unit(_) => vec![],
+                RustEnumVariant::Tuple { ty, .. } => vec![ty],
+                RustEnumVariant::AnonymousStruct { fields, .. } => {
+                    fields.iter().map(|f| &f.ty).collect()
+                }
+            })
+            .collect(),
+    }
+}
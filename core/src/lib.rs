@@ -1,8 +1,10 @@
 //! The core library for typeshare.
 //! Contains the parser and language converters.
 
-use language::Language;
-use std::io::Write;
+use language::{Language, LanguageFeature};
+use parser::ParsedData;
+use rust_types::{RustEnum, RustTypeFormatError};
+use std::io::{self, Write};
 use thiserror::Error;
 
 mod rename;
@@ -21,7 +23,72 @@ pub enum ProcessInputError {
     #[error("a parsing error occurred: {0}")]
     ParseError(#[from] parser::ParseError),
     #[error("a type generation error occurred: {0}")]
-    IoError(#[from] std::io::Error),
+    GenerationError(#[from] GenerationError),
+}
+
+/// An error that prevented code from being generated for parsed data.
+#[derive(Debug, Error)]
+pub enum GenerationError {
+    /// A type uses a construct that the target language can't represent.
+    #[error("`{type_name}` cannot be generated: {reason}")]
+    UnsupportedConstruct {
+        /// The name of the offending type.
+        type_name: String,
+        /// Why the construct can't be represented.
+        reason: String,
+    },
+    /// A type refers to a type that can't be mapped to the target language.
+    #[error("`{type_name}` cannot be generated: {source}")]
+    UnmappableType {
+        /// The name of the offending type.
+        type_name: String,
+        /// Why the referenced type can't be mapped.
+        source: RustTypeFormatError,
+    },
+    /// Writing the generated code failed.
+    #[error("failed to write generated code: {0}")]
+    Io(#[from] io::Error),
+}
+
+impl GenerationError {
+    /// Attribute an error returned while writing out the type named `type_name` to that type.
+    ///
+    /// Backends report unmappable types by wrapping a [`RustTypeFormatError`] in an
+    /// `io::Error`, and unsupported constructs as `io::ErrorKind::InvalidInput`.
+    pub(crate) fn for_type(error: io::Error, type_name: &str) -> Self {
+        if let Some(source) = error
+            .get_ref()
+            .and_then(|inner| inner.downcast_ref::<RustTypeFormatError>())
+        {
+            return Self::UnmappableType {
+                type_name: type_name.to_string(),
+                source: source.clone(),
+            };
+        }
+        if error.kind() == io::ErrorKind::InvalidInput {
+            return Self::UnsupportedConstruct {
+                type_name: type_name.to_string(),
+                reason: error.to_string(),
+            };
+        }
+        Self::Io(error)
+    }
+}
+
+/// A problem that would be hit while generating code for parsed data.
+#[derive(Debug, Error)]
+pub enum GenerationIssue {
+    /// An item uses a construct that the target language does not support.
+    #[error("`{item}` uses {feature:?}, which is not supported by the target language")]
+    UnsupportedFeature {
+        /// The name of the offending item.
+        item: String,
+        /// The unsupported construct.
+        feature: LanguageFeature,
+    },
+    /// Generating code failed.
+    #[error("a type generation error occurred: {0}")]
+    GenerationFailed(#[from] GenerationError),
 }
 
 /// Parse and generate types for a single Rust input file.
@@ -34,3 +101,56 @@ pub fn process_input(
     language.generate_types(out, &parsed_data)?;
     Ok(())
 }
+
+/// Generate types for already parsed data and return the generated code as a string.
+pub fn generate_string(
+    language: &mut dyn Language,
+    data: &ParsedData,
+) -> Result<String, GenerationError> {
+    let mut out = Vec::new();
+    language.generate_types(&mut out, data)?;
+    String::from_utf8(out).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e).into())
+}
+
+/// Check already parsed data against a language without producing any output.
+/// Returns every issue found; an empty list means generation will succeed.
+pub fn validate(language: &mut dyn Language, data: &ParsedData) -> Vec<GenerationIssue> {
+    let mut used_features = Vec::new();
+    for s in &data.structs {
+        if !s.generic_types.is_empty() {
+            used_features.push((&s.id.original, LanguageFeature::Generics));
+        }
+    }
+    for e in &data.enums {
+        let shared = e.shared();
+        used_features.push((&shared.id.original, LanguageFeature::Enums));
+        if let RustEnum::Algebraic { .. } = e {
+            used_features.push((&shared.id.original, LanguageFeature::AlgebraicEnums));
+        }
+        if !shared.generic_types.is_empty() {
+            used_features.push((&shared.id.original, LanguageFeature::Generics));
+        }
+    }
+    for a in &data.aliases {
+        used_features.push((&a.id.original, LanguageFeature::TypeAliases));
+        if !a.generic_types.is_empty() {
+            used_features.push((&a.id.original, LanguageFeature::Generics));
+        }
+    }
+
+    let mut issues: Vec<GenerationIssue> = used_features
+        .into_iter()
+        .filter(|(_, feature)| !language.supports(*feature))
+        .map(|(item, feature)| GenerationIssue::UnsupportedFeature {
+            item: item.clone(),
+            feature,
+        })
+        .collect();
+
+    // Run the real generation so that formatting errors are reported too.
+    if let Err(e) = language.generate_types(&mut io::sink(), data) {
+        issues.push(e.into());
+    }
+
+    issues
+}
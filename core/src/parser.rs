@@ -8,9 +8,8 @@ use crate::{
     },
 };
 use proc_macro2::{Ident, Span};
-use std::collections::BTreeSet;
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeSet, HashMap, HashSet},
     convert::TryFrom,
 };
 use syn::{Attribute, Fields, ItemEnum, ItemStruct, ItemType};
@@ -42,6 +41,101 @@ impl ParsedData {
         self.aliases.append(&mut other.aliases);
     }
 
+    /// Add the parsed data from `other` to `self`, skipping any type whose
+    /// name is already defined.
+    pub fn merge(&mut self, other: Self) {
+        let mut defined: HashSet<String> = self
+            .structs
+            .iter()
+            .map(|s| s.id.original.clone())
+            .chain(self.enums.iter().map(|e| e.shared().id.original.clone()))
+            .chain(self.aliases.iter().map(|a| a.id.original.clone()))
+            .collect();
+
+        for s in other.structs {
+            if defined.insert(s.id.original.clone()) {
+                self.structs.push(s);
+            }
+        }
+        for e in other.enums {
+            if defined.insert(e.shared().id.original.clone()) {
+                self.enums.push(e);
+            }
+        }
+        for a in other.aliases {
+            if defined.insert(a.id.original.clone()) {
+                self.aliases.push(a);
+            }
+        }
+    }
+
+    /// Sort the fields of every struct and struct variant alphabetically by their serialized name.
+    ///
+    /// Otherwise fields keep their declaration order, which is the order serde serializes them in.
+    pub fn sort_fields(&mut self) {
+        for s in &mut self.structs {
+            s.fields.sort_by(|a, b| a.id.renamed.cmp(&b.id.renamed));
+        }
+        for e in &mut self.enums {
+            let shared = match e {
+                RustEnum::Unit(shared) | RustEnum::Algebraic { shared, .. } => shared,
+            };
+            for variant in &mut shared.variants {
+                if let RustEnumVariant::AnonymousStruct { fields, .. } = variant {
+                    fields.sort_by(|a, b| a.id.renamed.cmp(&b.id.renamed));
+                }
+            }
+        }
+    }
+
+    /// Returns the names of the types that are referenced by fields, variants or type aliases,
+    /// but are neither defined in this data nor mapped in `type_mappings`.
+    ///
+    /// These are the types that need a mapping before types can be generated for them.
+    pub fn external_types(&self, type_mappings: &HashMap<String, String>) -> BTreeSet<String> {
+        let mut referenced = BTreeSet::new();
+
+        for s in &self.structs {
+            for field in &s.fields {
+                collect_referenced_types(&field.ty, &s.generic_types, &mut referenced);
+            }
+        }
+        for e in &self.enums {
+            let shared = e.shared();
+            for variant in &shared.variants {
+                match variant {
+                    RustEnumVariant::Unit(_) => {}
+                    RustEnumVariant::Tuple { ty, .. } => {
+                        collect_referenced_types(ty, &shared.generic_types, &mut referenced)
+                    }
+                    RustEnumVariant::AnonymousStruct { fields, .. } => {
+                        for field in fields {
+                            collect_referenced_types(
+                                &field.ty,
+                                &shared.generic_types,
+                                &mut referenced,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        for a in &self.aliases {
+            collect_referenced_types(&a.r#type, &a.generic_types, &mut referenced);
+        }
+
+        let defined: HashSet<&str> = self
+            .structs
+            .iter()
+            .map(|s| s.id.original.as_str())
+            .chain(self.enums.iter().map(|e| e.shared().id.original.as_str()))
+            .chain(self.aliases.iter().map(|a| a.id.original.as_str()))
+            .collect();
+
+        referenced.retain(|ty| !defined.contains(ty.as_str()) && !type_mappings.contains_key(ty));
+        referenced
+    }
+
     fn push_rust_thing(&mut self, rust_thing: RustItem) {
         match rust_thing {
             RustItem::Struct(s) => self.structs.push(s),
@@ -95,13 +189,13 @@ pub fn parse(input: &str) -> Result<ParsedData, ParseError> {
 
     for item in flatten_items(source.items.iter()) {
         match item {
-            syn::Item::Struct(s) if has_typeshare_annotation(&s.attrs) => {
+            syn::Item::Struct(s) if has_typeshare_annotation(&s.attrs) && !is_skipped(&s.attrs) => {
                 parsed_data.push_rust_thing(parse_struct(s)?);
             }
-            syn::Item::Enum(e) if has_typeshare_annotation(&e.attrs) => {
+            syn::Item::Enum(e) if has_typeshare_annotation(&e.attrs) && !is_skipped(&e.attrs) => {
                 parsed_data.push_rust_thing(parse_enum(e)?);
             }
-            syn::Item::Type(t) if has_typeshare_annotation(&t.attrs) => {
+            syn::Item::Type(t) if has_typeshare_annotation(&t.attrs) && !is_skipped(&t.attrs) => {
                 parsed_data.aliases.push(parse_type_alias(t)?);
             }
             _ => {}
@@ -111,6 +205,23 @@ pub fn parse(input: &str) -> Result<ParsedData, ParseError> {
     Ok(parsed_data)
 }
 
+/// Adds the names of all non-special types within `ty` to `referenced`, skipping generic parameters.
+fn collect_referenced_types(
+    ty: &RustType,
+    generic_types: &[String],
+    referenced: &mut BTreeSet<String>,
+) {
+    match ty {
+        RustType::Simple { id } | RustType::Generic { id, .. } if !generic_types.contains(id) => {
+            referenced.insert(id.clone());
+        }
+        _ => {}
+    }
+    for parameter in ty.parameters() {
+        collect_referenced_types(parameter, generic_types, referenced);
+    }
+}
+
 /// Given an iterator over items, will return an iterator that flattens the contents of embedded
 /// module items into the iterator.
 fn flatten_items<'a>(
@@ -161,10 +272,10 @@ fn parse_struct(s: &ItemStruct) -> Result<RustItem, ParseError> {
     Ok(match &s.fields {
         // Structs
         Fields::Named(f) => {
-            let fields = f
+            let mut fields: Vec<RustField> = f
                 .named
                 .iter()
-                .filter(|field| !is_skipped(&field.attrs))
+                .filter(|field| !is_skipped(&field.attrs) && !is_phantom_data(&field.ty))
                 .map(|f| {
                     let ty = if let Some(ty) = get_field_type_override(&f.attrs) {
                         ty.parse()?
@@ -177,6 +288,7 @@ fn parse_struct(s: &ItemStruct) -> Result<RustItem, ParseError> {
                     }
 
                     let has_default = serde_default(&f.attrs);
+                    let skip_serializing_if = serde_skip_serializing_if(&f.attrs);
                     let decorators = get_field_decorators(&f.attrs);
 
                     Ok(RustField {
@@ -184,11 +296,22 @@ fn parse_struct(s: &ItemStruct) -> Result<RustItem, ParseError> {
                         ty,
                         comments: parse_comment_attrs(&f.attrs),
                         has_default,
+                        skip_serializing_if,
                         decorators,
                     })
                 })
                 .collect::<Result<_, ParseError>>()?;
 
+            // A transparent struct is serialized exactly like its only field
+            if serde_transparent(&s.attrs) && fields.len() == 1 {
+                return Ok(RustItem::Alias(RustTypeAlias {
+                    id: get_ident(Some(&s.ident), &s.attrs, &None),
+                    r#type: fields.remove(0).ty,
+                    comments: parse_comment_attrs(&s.attrs),
+                    generic_types,
+                }));
+            }
+
             RustItem::Struct(RustStruct {
                 id: get_ident(Some(&s.ident), &s.attrs, &None),
                 generic_types,
@@ -366,6 +489,7 @@ fn parse_enum_variant(
             fields: fields_named
                 .named
                 .iter()
+                .filter(|f| !is_skipped(&f.attrs) && !is_phantom_data(&f.ty))
                 .map(|f| {
                     let field_type = if let Some(ty) = get_field_type_override(&f.attrs) {
                         ty.parse()?
@@ -374,6 +498,7 @@ fn parse_enum_variant(
                     };
 
                     let has_default = serde_default(&f.attrs);
+                    let skip_serializing_if = serde_skip_serializing_if(&f.attrs);
                     let decorators = get_field_decorators(&f.attrs);
 
                     Ok(RustField {
@@ -381,6 +506,7 @@ fn parse_enum_variant(
                         ty: field_type,
                         comments: parse_comment_attrs(&f.attrs),
                         has_default,
+                        skip_serializing_if,
                         decorators,
                     })
                 })
@@ -441,7 +567,64 @@ fn parse_comment_attrs(attrs: &[Attribute]) -> Vec<String> {
             _ => None,
         })
         .filter_map(literal_as_string)
-        .map(|string| string.trim().into())
+        .flat_map(|string| {
+            if string.contains('\n') {
+                // Block doc comments (and `#[doc = "..."]` strings) can span several lines.
+                // Split them so that no comment contains an embedded newline, the same way
+                // rustdoc does: the leading `*` that block comments conventionally start each
+                // line with is only stripped if every line has one, and only the indentation
+                // shared by all lines is removed, so that nested lists and indented code
+                // blocks keep their meaning. The blank lines left over from the `/**` and
+                // `*/` delimiters are dropped as well.
+                let mut lines: Vec<&str> = string.lines().map(str::trim_end).collect();
+                if lines
+                    .iter()
+                    .filter(|line| !line.is_empty())
+                    .all(|line| line.trim_start().starts_with('*'))
+                {
+                    lines = lines
+                        .into_iter()
+                        .map(|line| {
+                            let line = line.trim_start();
+                            line.strip_prefix('*').unwrap_or(line)
+                        })
+                        .collect();
+                }
+                // Text on the same line as the opening `/**` isn't indented like the rest
+                let indent = lines
+                    .iter()
+                    .skip(1)
+                    .filter(|line| !line.trim().is_empty())
+                    .map(|line| line.len() - line.trim_start().len())
+                    .min()
+                    .unwrap_or(0);
+                let lines: Vec<&str> = lines
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, line)| {
+                        if i == 0 {
+                            line.trim_start()
+                        } else {
+                            line.get(indent..).unwrap_or_else(|| line.trim_start())
+                        }
+                    })
+                    .collect();
+                let start = lines
+                    .iter()
+                    .position(|line| !line.trim().is_empty())
+                    .unwrap_or(lines.len());
+                let end = lines
+                    .iter()
+                    .rposition(|line| !line.trim().is_empty())
+                    .map_or(start, |i| i + 1);
+                lines[start..end]
+                    .iter()
+                    .map(|line| line.to_string())
+                    .collect()
+            } else {
+                vec![string.trim().to_string()]
+            }
+        })
         .collect()
 }
 
@@ -674,10 +857,20 @@ fn serde_default(attrs: &[syn::Attribute]) -> bool {
     serde_attr(attrs, &Ident::new("default", Span::call_site()))
 }
 
+fn serde_skip_serializing_if(attrs: &[syn::Attribute]) -> bool {
+    get_serde_name_value_meta_items(attrs, "skip_serializing_if")
+        .next()
+        .is_some()
+}
+
 fn serde_flatten(attrs: &[syn::Attribute]) -> bool {
     serde_attr(attrs, &Ident::new("flatten", Span::call_site()))
 }
 
+fn serde_transparent(attrs: &[syn::Attribute]) -> bool {
+    serde_attr(attrs, &Ident::new("transparent", Span::call_site()))
+}
+
 // TODO: for now, this is a workaround until we can integrate serde_derive_internal
 // into our parser.
 /// Returns all arguments passed into `#[serde(...)]` attributes
@@ -704,6 +897,19 @@ pub fn get_typeshare_meta_items(attr: &syn::Attribute) -> Vec<NestedMeta> {
     }
 }
 
+/// Checks if the given type is `PhantomData`, which has no serialized representation
+fn is_phantom_data(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "PhantomData")
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
 // `#[typeshare(skip)]` or `#[serde(skip)]`
 fn is_skipped(attrs: &[syn::Attribute]) -> bool {
     let skip = Ident::new("skip", Span::call_site());
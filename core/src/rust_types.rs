@@ -74,6 +74,9 @@ pub struct RustField {
     /// Even if the field's type is not optional, we need to make it optional
     /// for the languages we generate code for.
     pub has_default: bool,
+    /// This will be true if the field has a `serde(skip_serializing_if = "...")` decorator,
+    /// in which case it may be missing from the serialized data.
+    pub skip_serializing_if: bool,
     /// Language-specific decorators assigned to a given field.
     /// The keys are language names (e.g. SupportedLanguage::TypeScript), the values are field decorators (e.g. readonly)
     pub decorators: HashMap<SupportedLanguage, BTreeSet<FieldDecorator>>,
@@ -137,8 +140,10 @@ pub enum SpecialRustType {
     Array(Box<RustType>, usize),
     /// Represents `&[T]` from the standard library
     Slice(Box<RustType>),
-    /// Represents `HashMap<K, V>` from the standard library
+    /// Represents `HashMap<K, V>`, `BTreeMap<K, V>` or `IndexMap<K, V>`
     HashMap(Box<RustType>, Box<RustType>),
+    /// Represents `HashSet<T>` or `BTreeSet<T>` from the standard library
+    HashSet(Box<RustType>),
     /// Represents `Option<T>` from the standard library
     Option(Box<RustType>),
     /// Represents `()`
@@ -236,29 +241,42 @@ impl TryFrom<&syn::Type> for RustType {
                     "Option" => Self::Special(SpecialRustType::Option(
                         parameters.into_iter().next().unwrap().into(),
                     )),
-                    "HashMap" => {
+                    // Ordered maps serialize the same way as `HashMap`.
+                    "HashMap" | "BTreeMap" | "IndexMap" => {
                         let mut params = parameters.into_iter();
                         Self::Special(SpecialRustType::HashMap(
                             params.next().unwrap().into(),
                             params.next().unwrap().into(),
                         ))
                     }
+                    "HashSet" | "BTreeSet" => Self::Special(SpecialRustType::HashSet(
+                        parameters.into_iter().next().unwrap().into(),
+                    )),
                     "str" | "String" => Self::Special(SpecialRustType::String),
-                    // Since we do not need to box types in other languages, we treat this type
-                    // as its inner type.
-                    "Box" => parameters.into_iter().next().unwrap(),
+                    // Since we do not need to box, reference count or borrow types in other
+                    // languages, we treat these types as their inner type.
+                    "Box" | "Rc" | "Arc" | "Cow" => parameters.into_iter().next().unwrap(),
                     "bool" => Self::Special(SpecialRustType::Bool),
                     "char" => Self::Special(SpecialRustType::Char),
-                    "u8" => Self::Special(SpecialRustType::U8),
-                    "u16" => Self::Special(SpecialRustType::U16),
-                    "u32" => Self::Special(SpecialRustType::U32),
+                    // `NonZero*` integers serialize as their inner integer type.
+                    "u8" | "NonZeroU8" => Self::Special(SpecialRustType::U8),
+                    "u16" | "NonZeroU16" => Self::Special(SpecialRustType::U16),
+                    "u32" | "NonZeroU32" => Self::Special(SpecialRustType::U32),
                     "U53" => Self::Special(SpecialRustType::U53),
-                    "u64" | "i64" | "usize" | "isize" => {
+                    "u64" | "i64" | "usize" | "isize" | "NonZeroU64" | "NonZeroI64"
+                    | "NonZeroUsize" | "NonZeroIsize" => {
                         return Err(RustTypeParseError::UnsupportedType(vec![id]))
                     }
-                    "i8" => Self::Special(SpecialRustType::I8),
-                    "i16" => Self::Special(SpecialRustType::I16),
-                    "i32" => Self::Special(SpecialRustType::I32),
+                    // 128 bit integers aren't special, so they are left to the type mappings.
+                    "NonZeroU128" => Self::Simple {
+                        id: "u128".to_string(),
+                    },
+                    "NonZeroI128" => Self::Simple {
+                        id: "i128".to_string(),
+                    },
+                    "i8" | "NonZeroI8" => Self::Special(SpecialRustType::I8),
+                    "i16" | "NonZeroI16" => Self::Special(SpecialRustType::I16),
+                    "i32" | "NonZeroI32" => Self::Special(SpecialRustType::I32),
                     "I54" => Self::Special(SpecialRustType::I54),
                     "f32" => Self::Special(SpecialRustType::F32),
                     "f64" => Self::Special(SpecialRustType::F64),
@@ -368,24 +386,40 @@ impl RustField {
                 _ => None,
             })
     }
+
+    /// Whether this field can be missing from the serialized data, even if its
+    /// type is not optional.
+    pub fn may_be_absent(&self) -> bool {
+        self.has_default || self.skip_serializing_if
+    }
 }
 
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Error)]
 #[allow(missing_docs)]
 pub enum RustTypeFormatError {
     #[error("Generic parameter `{0}` is forbidden in Go")]
     GenericsForbiddenInGo(String),
     #[error("Generic type `{0}` cannot be used as a map key in Typescript")]
     GenericKeyForbiddenInTS(String),
+    #[error("64 bit type `{0}` cannot be represented in Typescript")]
+    UnsupportedTypeInTS(String),
+    #[error("`{0}` cannot be represented in GraphQL")]
+    UnsupportedTypeInGraphQL(String),
+    #[error("`{0}` cannot be represented in C#")]
+    UnsupportedTypeInCSharp(String),
+    #[error("`{0}` cannot be represented in Protobuf")]
+    UnsupportedTypeInProtobuf(String),
 }
 
 impl SpecialRustType {
     /// Check if this type is equivalent to or contains `ty` in one of its generic parameters.
     pub fn contains_type(&self, ty: &str) -> bool {
         match &self {
-            Self::Vec(rty) | Self::Array(rty, _) | Self::Slice(rty) | Self::Option(rty) => {
-                rty.contains_type(ty)
-            }
+            Self::Vec(rty)
+            | Self::Array(rty, _)
+            | Self::Slice(rty)
+            | Self::Option(rty)
+            | Self::HashSet(rty) => rty.contains_type(ty),
             Self::HashMap(rty1, rty2) => rty1.contains_type(ty) || rty2.contains_type(ty),
             Self::Unit
             | Self::String
@@ -419,6 +453,7 @@ impl SpecialRustType {
             Self::Slice(_) => "&[]",
             Self::Option(_) => "Option",
             Self::HashMap(_, _) => "HashMap",
+            Self::HashSet(_) => "HashSet",
             Self::String => "String",
             Self::Char => "char",
             Self::Bool => "bool",
@@ -440,9 +475,11 @@ impl SpecialRustType {
     /// if there are none.
     pub fn parameters(&self) -> Box<dyn Iterator<Item = &RustType> + '_> {
         match &self {
-            Self::Vec(rtype) | Self::Array(rtype, _) | Self::Slice(rtype) | Self::Option(rtype) => {
-                Box::new(std::iter::once(rtype.as_ref()))
-            }
+            Self::Vec(rtype)
+            | Self::Array(rtype, _)
+            | Self::Slice(rtype)
+            | Self::Option(rtype)
+            | Self::HashSet(rtype) => Box::new(std::iter::once(rtype.as_ref())),
             Self::HashMap(rtype1, rtype2) => {
                 Box::new([rtype1.as_ref(), rtype2.as_ref()].into_iter())
             }
@@ -591,3 +628,14 @@ pub enum RustItem {
     /// A `type` definition or newtype struct.
     Alias(RustTypeAlias),
 }
+
+impl RustItem {
+    /// The identifier of the defined type.
+    pub fn id(&self) -> &Id {
+        match self {
+            Self::Alias(a) => &a.id,
+            Self::Struct(s) => &s.id,
+            Self::Enum(e) => &e.shared().id,
+        }
+    }
+}
@@ -7,9 +7,15 @@ use crate::rust_types::{
 fn get_dependencies_from_type(
     tp: &RustType,
     types: &HashMap<String, &RustItem>,
+    generic_types: &[String],
     res: &mut Vec<String>,
     seen: &mut HashSet<String>,
 ) {
+    // Generic parameters are placeholders, not references to a type that
+    // happens to share their name
+    if generic_types.iter().any(|g| g == tp.id()) {
+        return;
+    }
     match tp {
         RustType::Generic { id, parameters } => {
             if let Some(&tp) = types.get(id) {
@@ -18,6 +24,9 @@ fn get_dependencies_from_type(
                     get_dependencies(tp, types, res, seen);
                     for parameter in parameters {
                         let id = parameter.id().to_string();
+                        if generic_types.contains(&id) {
+                            continue;
+                        }
                         if let Some(&tp) = types.get(&id) {
                             if seen.insert(id.clone()) {
                                 res.push(id.clone());
@@ -41,14 +50,17 @@ fn get_dependencies_from_type(
         }
         RustType::Special(special) => match special {
             SpecialRustType::HashMap(kt, vt) => {
-                get_dependencies_from_type(kt, types, res, seen);
-                get_dependencies_from_type(vt, types, res, seen);
+                get_dependencies_from_type(kt, types, generic_types, res, seen);
+                get_dependencies_from_type(vt, types, generic_types, res, seen);
             }
             SpecialRustType::Option(inner) => {
-                get_dependencies_from_type(inner, types, res, seen);
+                get_dependencies_from_type(inner, types, generic_types, res, seen);
             }
             SpecialRustType::Vec(inner) => {
-                get_dependencies_from_type(inner, types, res, seen);
+                get_dependencies_from_type(inner, types, generic_types, res, seen);
+            }
+            SpecialRustType::HashSet(inner) => {
+                get_dependencies_from_type(inner, types, generic_types, res, seen);
             }
             _ => {}
         },
@@ -70,16 +82,22 @@ fn get_enum_dependencies(
             shared,
         } => {
             if seen.insert(shared.id.original.to_string()) {
-                res.push(shared.id.original.to_string());
                 for variant in &shared.variants {
                     match variant {
                         RustEnumVariant::Unit(_) => {}
-                        RustEnumVariant::AnonymousStruct {
-                            fields: _,
-                            shared: _,
-                        } => {}
+                        RustEnumVariant::AnonymousStruct { fields, shared: _ } => {
+                            for field in fields {
+                                get_dependencies_from_type(
+                                    &field.ty,
+                                    types,
+                                    &shared.generic_types,
+                                    res,
+                                    seen,
+                                )
+                            }
+                        }
                         RustEnumVariant::Tuple { ty, shared: _ } => {
-                            get_dependencies_from_type(ty, types, res, seen)
+                            get_dependencies_from_type(ty, types, &shared.generic_types, res, seen)
                         }
                     }
                 }
@@ -97,7 +115,7 @@ fn get_struct_dependencies(
 ) {
     if seen.insert(strct.id.original.to_string()) {
         for field in &strct.fields {
-            get_dependencies_from_type(&field.ty, types, res, seen)
+            get_dependencies_from_type(&field.ty, types, &strct.generic_types, res, seen)
         }
         seen.remove(&strct.id.original.to_string());
     }
@@ -110,12 +128,7 @@ fn get_type_alias_dependencies(
     seen: &mut HashSet<String>,
 ) {
     if seen.insert(ta.id.original.to_string()) {
-        get_dependencies_from_type(&ta.r#type, types, res, seen);
-        for generic in &ta.generic_types {
-            if let Some(&thing) = types.get(generic) {
-                get_dependencies(thing, types, res, seen)
-            }
-        }
+        get_dependencies_from_type(&ta.r#type, types, &ta.generic_types, res, seen);
         seen.remove(&ta.id.original.to_string());
     }
 }
@@ -224,3 +237,92 @@ fn test_toposort_impl_cycles() {
     let res = toposort_impl(&dag);
     assert!((res == vec![0, 1, 2]) || (res == vec![1, 0, 2]))
 }
+
+#[test]
+fn test_topsort_ignores_generic_parameters() {
+    use crate::rust_types::{Id, RustField};
+
+    fn id(name: &str) -> Id {
+        Id {
+            original: name.to_string(),
+            renamed: name.to_string(),
+        }
+    }
+
+    // `Wrapper<T>` refers to its parameter `T`, which must not be mistaken
+    // for the unrelated struct that is also called `T`.
+    let wrapper = RustItem::Struct(RustStruct {
+        id: id("Wrapper"),
+        generic_types: vec!["T".to_string()],
+        fields: vec![RustField {
+            id: id("value"),
+            ty: RustType::Simple {
+                id: "T".to_string(),
+            },
+            comments: vec![],
+            has_default: false,
+            skip_serializing_if: false,
+            decorators: HashMap::new(),
+        }],
+        comments: vec![],
+        decorators: HashMap::new(),
+    });
+    let t = RustItem::Struct(RustStruct {
+        id: id("T"),
+        generic_types: vec![],
+        fields: vec![],
+        comments: vec![],
+        decorators: HashMap::new(),
+    });
+
+    let sorted = topsort(vec![&wrapper, &t]);
+    assert_eq!(sorted, vec![&wrapper, &t]);
+}
+
+#[test]
+fn test_topsort_orders_anonymous_struct_variants_after_field_types() {
+    let parsed = crate::parser::parse(
+        r#"
+        #[typeshare]
+        #[serde(tag = "type", content = "content")]
+        pub enum Event {
+            Created { item: Item },
+        }
+
+        #[typeshare]
+        pub struct Item {
+            id: String,
+        }
+        "#,
+    )
+    .unwrap();
+    let event = RustItem::Enum(parsed.enums[0].clone());
+    let item = RustItem::Struct(parsed.structs[0].clone());
+
+    let sorted = topsort(vec![&event, &item]);
+    assert_eq!(sorted, vec![&item, &event]);
+}
+
+#[test]
+fn test_topsort_orders_tuple_variants_after_their_types() {
+    let parsed = crate::parser::parse(
+        r#"
+        #[typeshare]
+        #[serde(tag = "type", content = "content")]
+        pub enum Event {
+            Created(Item),
+        }
+
+        #[typeshare]
+        pub struct Item {
+            id: String,
+        }
+        "#,
+    )
+    .unwrap();
+    let event = RustItem::Enum(parsed.enums[0].clone());
+    let item = RustItem::Struct(parsed.structs[0].clone());
+
+    let sorted = topsort(vec![&event, &item]);
+    assert_eq!(sorted, vec![&item, &event]);
+}
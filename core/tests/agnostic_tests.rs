@@ -1,8 +1,27 @@
 use typeshare_core::{
-    language::TypeScript, parser::ParseError, process_input, rust_types::RustTypeParseError,
-    ProcessInputError,
+    generate_string,
+    language::{Go, GraphQL, Language, LanguageFeature, Scala, Swift, TypeScript},
+    parser::{parse, ParseError},
+    process_input,
+    rust_types::{RustType, RustTypeParseError, SpecialRustType},
+    validate, GenerationError, GenerationIssue, ProcessInputError,
 };
 
+fn assert_field_type(ty: &str, expected: RustType) {
+    let source = format!(
+        r##"
+    #[typeshare]
+    pub struct Foo {{
+        pub bar: {ty},
+    }}
+    "##,
+        ty = ty
+    );
+
+    let parsed = parse(&source).unwrap();
+    assert_eq!(parsed.structs[0].fields[0].ty, expected);
+}
+
 mod blocklisted_types {
     use super::*;
 
@@ -47,6 +66,11 @@ mod blocklisted_types {
         assert_type_is_blocklisted("usize", "usize");
     }
 
+    #[test]
+    fn test_non_zero_u64_blocklisted_struct() {
+        assert_type_is_blocklisted("NonZeroU64", "NonZeroU64");
+    }
+
     #[test]
     fn test_optional_blocklisted_struct() {
         assert_type_is_blocklisted("Option<i64>", "i64");
@@ -120,3 +144,755 @@ mod serde_attributes_on_enums {
         ));
     }
 }
+
+mod smart_pointers {
+    use super::*;
+
+    #[test]
+    fn smart_pointers_are_transparent() {
+        for ty in [
+            "Box<String>",
+            "Rc<String>",
+            "Arc<String>",
+            "std::rc::Rc<String>",
+            "std::sync::Arc<String>",
+        ] {
+            assert_field_type(ty, RustType::Special(SpecialRustType::String));
+        }
+    }
+
+    #[test]
+    fn borrowed_types_resolve_to_owned() {
+        for ty in [
+            "Cow<'a, str>",
+            "std::borrow::Cow<'a, str>",
+            "&'a str",
+            "&str",
+        ] {
+            assert_field_type(ty, RustType::Special(SpecialRustType::String));
+        }
+        assert_field_type(
+            "&'a Bar",
+            RustType::Simple {
+                id: "Bar".to_string(),
+            },
+        );
+        assert_field_type(
+            "Cow<'a, [u32]>",
+            RustType::Special(SpecialRustType::Slice(Box::new(RustType::Special(
+                SpecialRustType::U32,
+            )))),
+        );
+    }
+
+    #[test]
+    fn nested_smart_pointers_are_transparent() {
+        assert_field_type(
+            "Vec<Arc<Box<Bar>>>",
+            RustType::Special(SpecialRustType::Vec(Box::new(RustType::Simple {
+                id: "Bar".to_string(),
+            }))),
+        );
+    }
+}
+
+mod nonzero {
+    use super::*;
+
+    #[test]
+    fn non_zero_integers_resolve_to_inner_type() {
+        assert_field_type("NonZeroU32", RustType::Special(SpecialRustType::U32));
+        assert_field_type(
+            "std::num::NonZeroI8",
+            RustType::Special(SpecialRustType::I8),
+        );
+    }
+
+    #[test]
+    fn non_zero_128_bit_integers_resolve_to_inner_type() {
+        assert_field_type(
+            "NonZeroU128",
+            RustType::Simple {
+                id: "u128".to_string(),
+            },
+        );
+        assert_field_type(
+            "NonZeroI128",
+            RustType::Simple {
+                id: "i128".to_string(),
+            },
+        );
+    }
+}
+
+mod maps {
+    use super::*;
+
+    #[test]
+    fn ordered_maps_resolve_to_hashmap() {
+        for ty in [
+            "HashMap<String, u32>",
+            "BTreeMap<String, u32>",
+            "indexmap::IndexMap<String, u32>",
+        ] {
+            assert_field_type(
+                ty,
+                RustType::Special(SpecialRustType::HashMap(
+                    Box::new(RustType::Special(SpecialRustType::String)),
+                    Box::new(RustType::Special(SpecialRustType::U32)),
+                )),
+            );
+        }
+    }
+}
+
+mod phantom_data {
+    use super::*;
+
+    #[test]
+    fn phantom_data_fields_are_dropped() {
+        let source = r##"
+    #[typeshare]
+    pub struct Foo<T> {
+        pub bar: String,
+        pub marker: std::marker::PhantomData<T>,
+    }
+    "##;
+
+        let parsed = parse(source).unwrap();
+        let fields = &parsed.structs[0].fields;
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].id.original, "bar");
+    }
+}
+
+mod generic_bounds {
+    use super::*;
+
+    #[test]
+    fn bounds_are_stripped_from_generic_names() {
+        let source = r##"
+    #[typeshare]
+    pub struct Foo<'a, T: Serialize + Clone, U: Into<String> + 'a>
+    where
+        T: Debug,
+    {
+        pub t: T,
+        pub u: U,
+    }
+
+    #[typeshare]
+    #[serde(tag = "type", content = "content")]
+    pub enum Bar<T: Serialize, U>
+    where
+        U: Clone,
+    {
+        T(T),
+        U(U),
+    }
+
+    #[typeshare]
+    pub type Baz<T: Clone> = Vec<T>;
+    "##;
+
+        let parsed = parse(source).unwrap();
+        assert_eq!(parsed.structs[0].generic_types, vec!["T", "U"]);
+        assert_eq!(parsed.enums[0].shared().generic_types, vec!["T", "U"]);
+        assert_eq!(parsed.aliases[0].generic_types, vec!["T"]);
+    }
+}
+
+mod skipped_fields {
+    use super::*;
+    use typeshare_core::rust_types::RustEnumVariant;
+
+    #[test]
+    fn skipped_anonymous_struct_variant_fields_are_dropped() {
+        let source = r##"
+    #[typeshare]
+    #[serde(tag = "type", content = "content")]
+    pub enum Foo {
+        Bar {
+            visible: String,
+            #[typeshare(skip)]
+            hidden: String,
+            #[serde(skip)]
+            also_hidden: String,
+        },
+    }
+    "##;
+
+        let parsed = parse(source).unwrap();
+        match &parsed.enums[0].shared().variants[0] {
+            RustEnumVariant::AnonymousStruct { fields, .. } => {
+                assert_eq!(fields.len(), 1);
+                assert_eq!(fields[0].id.original, "visible");
+            }
+            variant => panic!("expected an anonymous struct variant, got {:?}", variant),
+        }
+    }
+}
+
+mod skipped_types {
+    use super::*;
+
+    #[test]
+    fn skipped_items_are_not_generated() {
+        let source = r##"
+    #[typeshare]
+    pub struct Visible {
+        pub name: String,
+    }
+
+    #[typeshare(skip)]
+    pub struct Hidden {
+        pub name: String,
+    }
+
+    #[typeshare(skip)]
+    pub enum HiddenEnum {
+        A,
+    }
+
+    #[typeshare(skip)]
+    pub type HiddenAlias = String;
+    "##;
+
+        let parsed = parse(source).unwrap();
+        assert_eq!(parsed.structs.len(), 1);
+        assert!(parsed.enums.is_empty());
+        assert!(parsed.aliases.is_empty());
+        assert_eq!(
+            generate_string(
+                &mut TypeScript {
+                    no_version_header: true,
+                    ..Default::default()
+                },
+                &parsed
+            )
+            .unwrap(),
+            "export interface Visible {\n\tname: string;\n}\n\n"
+        );
+    }
+}
+
+mod generation_errors {
+    use super::*;
+    use typeshare_core::rust_types::RustTypeFormatError;
+
+    #[test]
+    fn scala_without_package_is_an_error() {
+        let source = r##"
+    #[typeshare]
+    pub struct Foo {
+        pub bar: String,
+    }
+    "##;
+
+        let mut out: Vec<u8> = Vec::new();
+        assert!(matches!(
+            process_input(source, &mut Scala::default(), &mut out),
+            Err(ProcessInputError::GenerationError(GenerationError::Io(e)))
+                if e.kind() == std::io::ErrorKind::InvalidInput
+        ));
+    }
+
+    #[test]
+    fn graphql_empty_struct_is_an_error() {
+        let source = r##"
+    #[typeshare]
+    pub struct Foo {}
+    "##;
+
+        let mut out: Vec<u8> = Vec::new();
+        assert!(matches!(
+            process_input(source, &mut GraphQL::default(), &mut out),
+            Err(ProcessInputError::GenerationError(GenerationError::UnsupportedConstruct {
+                type_name,
+                ..
+            })) if type_name == "Foo"
+        ));
+    }
+
+    #[test]
+    fn unmappable_types_are_reported_with_the_offending_type() {
+        let source = r##"
+    #[typeshare]
+    pub struct Foo {
+        pub bar: Wrapper<String>,
+    }
+    "##;
+
+        let mut out: Vec<u8> = Vec::new();
+        assert!(matches!(
+            process_input(source, &mut GraphQL::default(), &mut out),
+            Err(ProcessInputError::GenerationError(GenerationError::UnmappableType {
+                type_name,
+                source: RustTypeFormatError::UnsupportedTypeInGraphQL(ty),
+            })) if type_name == "Foo" && ty == "Wrapper"
+        ));
+    }
+
+    #[test]
+    fn graphql_generic_types_are_an_error() {
+        let ty = RustType::Generic {
+            id: "Wrapper".to_string(),
+            parameters: vec![RustType::Special(SpecialRustType::String)],
+        };
+        assert!(matches!(
+            GraphQL::default().format_type(&ty, &[]),
+            Err(RustTypeFormatError::UnsupportedTypeInGraphQL(ty)) if ty == "Wrapper"
+        ));
+    }
+
+    #[test]
+    fn typescript_64_bit_types_are_an_error() {
+        assert!(matches!(
+            TypeScript::default().format_special_type(&SpecialRustType::I64, &[]),
+            Err(RustTypeFormatError::UnsupportedTypeInTS(ty)) if ty == "i64"
+        ));
+    }
+}
+
+mod serde_transparent {
+    use super::*;
+
+    #[test]
+    fn transparent_struct_is_an_alias() {
+        let source = r##"
+    /// A user's unique identifier
+    #[typeshare]
+    #[serde(transparent)]
+    pub struct UserId {
+        id: String,
+    }
+    "##;
+
+        let parsed = parse(source).unwrap();
+        assert!(parsed.structs.is_empty());
+        let alias = &parsed.aliases[0];
+        assert_eq!(alias.id.original, "UserId");
+        assert_eq!(alias.r#type, RustType::Special(SpecialRustType::String));
+        assert_eq!(
+            alias.comments,
+            vec!["A user's unique identifier".to_string()]
+        );
+    }
+
+    #[test]
+    fn transparent_newtype_is_an_alias() {
+        let source = r##"
+    #[typeshare]
+    #[serde(transparent)]
+    pub struct UserId(String);
+    "##;
+
+        let parsed = parse(source).unwrap();
+        assert_eq!(
+            parsed.aliases[0].r#type,
+            RustType::Special(SpecialRustType::String)
+        );
+    }
+}
+
+mod collection_aliases {
+    use super::*;
+
+    #[test]
+    fn aliases_of_collections_resolve_to_special_types() {
+        let source = r##"
+    #[typeshare]
+    pub type StringMap = HashMap<String, String>;
+
+    #[typeshare]
+    pub type Names = Vec<String>;
+    "##;
+
+        let parsed = parse(source).unwrap();
+        assert_eq!(
+            parsed.aliases[0].r#type,
+            RustType::Special(SpecialRustType::HashMap(
+                Box::new(RustType::Special(SpecialRustType::String)),
+                Box::new(RustType::Special(SpecialRustType::String)),
+            ))
+        );
+        assert_eq!(
+            parsed.aliases[1].r#type,
+            RustType::Special(SpecialRustType::Vec(Box::new(RustType::Special(
+                SpecialRustType::String
+            ))))
+        );
+        assert_eq!(
+            generate_string(
+                &mut TypeScript {
+                    no_version_header: true,
+                    ..Default::default()
+                },
+                &parsed
+            )
+            .unwrap(),
+            "export type StringMap = Record<string, string>;\n\nexport type Names = string[];\n\n"
+        );
+    }
+}
+
+mod multi_line_comments {
+    use super::*;
+
+    #[test]
+    fn block_doc_comments_are_split_into_lines() {
+        let source = r##"
+    /**
+        First line
+        Second line
+        Third line
+    */
+    #[typeshare]
+    pub struct Foo {
+        #[doc = "One\nTwo"]
+        pub bar: String,
+    }
+    "##;
+
+        let parsed = parse(source).unwrap();
+        assert_eq!(
+            parsed.structs[0].comments,
+            vec!["First line", "Second line", "Third line"]
+        );
+        assert_eq!(parsed.structs[0].fields[0].comments, vec!["One", "Two"]);
+    }
+
+    #[test]
+    fn block_doc_comments_keep_nested_indentation() {
+        let source = r##"
+    /**
+     * Options:
+     *
+     * - first
+     *   continued
+     *
+     *     let code = 1;
+     */
+    #[typeshare]
+    pub struct Foo {
+        /**
+            Some text
+            * emphasised
+        */
+        pub bar: String,
+    }
+    "##;
+
+        let parsed = parse(source).unwrap();
+        assert_eq!(
+            parsed.structs[0].comments,
+            vec![
+                "Options:",
+                "",
+                "- first",
+                "  continued",
+                "",
+                "    let code = 1;"
+            ]
+        );
+        assert_eq!(
+            parsed.structs[0].fields[0].comments,
+            vec!["Some text", "* emphasised"]
+        );
+    }
+
+    #[test]
+    fn block_doc_comments_are_written_as_separate_lines() {
+        let source = r##"
+    /** First line
+     Second line
+     Third line */
+    #[typeshare]
+    pub struct Foo {
+        pub bar: String,
+    }
+    "##;
+
+        let mut out = Vec::new();
+        process_input(
+            source,
+            &mut TypeScript {
+                no_version_header: true,
+                ..Default::default()
+            },
+            &mut out,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "/**\n * First line\n * Second line\n * Third line\n */\nexport interface Foo {\n\tbar: string;\n}\n\n"
+        );
+    }
+}
+
+mod language_features {
+    use super::*;
+
+    #[test]
+    fn go_does_not_support_generics() {
+        let go = Go::default();
+        assert!(!go.supports(LanguageFeature::Generics));
+        assert!(go.supports(LanguageFeature::Enums));
+        assert!(go.supports(LanguageFeature::TypeAliases));
+    }
+
+    #[test]
+    fn other_languages_support_everything() {
+        for feature in [
+            LanguageFeature::Enums,
+            LanguageFeature::AlgebraicEnums,
+            LanguageFeature::Generics,
+            LanguageFeature::TypeAliases,
+        ] {
+            assert!(TypeScript::default().supports(feature));
+            assert!(Swift::default().supports(feature));
+        }
+    }
+}
+
+mod name_mangling {
+    use super::*;
+
+    #[test]
+    fn names_are_unchanged_by_default() {
+        let go = Go::default();
+        assert_eq!(go.mangle_type_name("Foo"), "Foo");
+        assert_eq!(go.mangle_field_name("some-field"), "some-field");
+    }
+
+    #[test]
+    fn typescript_quotes_field_names_with_dashes() {
+        let typescript = TypeScript::default();
+        assert_eq!(typescript.mangle_field_name("some-field"), "\"some-field\"");
+        assert_eq!(typescript.mangle_field_name("someField"), "someField");
+    }
+}
+
+mod field_order {
+    use super::*;
+    use typeshare_core::rust_types::RustEnumVariant;
+
+    #[test]
+    fn declaration_order_is_preserved() {
+        let source = r##"
+    #[typeshare]
+    pub struct Foo {
+        pub zebra: String,
+        pub apple: u32,
+        pub mango: bool,
+    }
+    "##;
+
+        let parsed = parse(source).unwrap();
+        let fields: Vec<&str> = parsed.structs[0]
+            .fields
+            .iter()
+            .map(|f| f.id.original.as_str())
+            .collect();
+        assert_eq!(fields, vec!["zebra", "apple", "mango"]);
+
+        let mut out = Vec::new();
+        process_input(
+            source,
+            &mut TypeScript {
+                no_version_header: true,
+                ..Default::default()
+            },
+            &mut out,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "export interface Foo {\n\tzebra: string;\n\tapple: number;\n\tmango: boolean;\n}\n\n"
+        );
+    }
+
+    #[test]
+    fn fields_can_be_sorted_alphabetically() {
+        let source = r##"
+    #[typeshare]
+    #[serde(rename_all = "camelCase")]
+    pub struct Foo {
+        pub zebra: String,
+        pub apple: u32,
+        #[serde(rename = "banana")]
+        pub mango: bool,
+    }
+
+    #[typeshare]
+    #[serde(tag = "type", content = "content")]
+    pub enum Bar {
+        Baz { zebra: String, apple: u32 },
+    }
+    "##;
+
+        let mut parsed = parse(source).unwrap();
+        parsed.sort_fields();
+        let fields: Vec<&str> = parsed.structs[0]
+            .fields
+            .iter()
+            .map(|f| f.id.renamed.as_str())
+            .collect();
+        assert_eq!(fields, vec!["apple", "banana", "zebra"]);
+
+        match &parsed.enums[0].shared().variants[0] {
+            RustEnumVariant::AnonymousStruct { fields, .. } => {
+                let fields: Vec<&str> = fields.iter().map(|f| f.id.renamed.as_str()).collect();
+                assert_eq!(fields, vec!["apple", "zebra"]);
+            }
+            variant => panic!("unexpected variant {:?}", variant),
+        }
+    }
+}
+
+mod validation {
+    use super::*;
+
+    const SOURCE: &str = r##"
+    #[typeshare]
+    pub struct Wrapper<T> {
+        pub inner: T,
+    }
+
+    #[typeshare]
+    #[serde(tag = "type", content = "content")]
+    pub enum Shape {
+        Circle(u32),
+        Square(u32),
+    }
+    "##;
+
+    #[test]
+    fn valid_input_has_no_issues() {
+        let parsed = parse(SOURCE).unwrap();
+        assert!(validate(&mut TypeScript::default(), &parsed).is_empty());
+    }
+
+    #[test]
+    fn reports_unsupported_features() {
+        let parsed = parse(SOURCE).unwrap();
+        let issues = validate(&mut Go::default(), &parsed);
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(
+            &issues[0],
+            GenerationIssue::UnsupportedFeature {
+                item,
+                feature: LanguageFeature::Generics,
+            } if item == "Wrapper"
+        ));
+    }
+
+    #[test]
+    fn reports_unsupported_enums_alongside_generation_errors() {
+        let parsed = parse(SOURCE).unwrap();
+        let issues = validate(&mut GraphQL::default(), &parsed);
+        assert_eq!(issues.len(), 3);
+        assert!(matches!(
+            &issues[1],
+            GenerationIssue::UnsupportedFeature {
+                item,
+                feature: LanguageFeature::AlgebraicEnums,
+            } if item == "Shape"
+        ));
+        assert!(matches!(&issues[2], GenerationIssue::GenerationFailed(_)));
+    }
+
+    #[test]
+    fn reports_generation_errors() {
+        let parsed = parse(SOURCE).unwrap();
+        let issues = validate(&mut Scala::default(), &parsed);
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(&issues[0], GenerationIssue::GenerationFailed(_)));
+    }
+}
+
+mod merging {
+    use super::*;
+
+    #[test]
+    fn merged_data_is_generated_together() {
+        let mut parsed = parse(
+            r##"
+    #[typeshare]
+    pub struct Foo {
+        pub bar: Bar,
+    }
+    "##,
+        )
+        .unwrap();
+        let other = parse(
+            r##"
+    #[typeshare]
+    pub struct Bar {
+        pub baz: String,
+    }
+
+    #[typeshare]
+    pub struct Foo {
+        pub duplicate: bool,
+    }
+    "##,
+        )
+        .unwrap();
+
+        parsed.merge(other);
+        assert_eq!(parsed.structs.len(), 2);
+        assert_eq!(
+            generate_string(
+                &mut TypeScript {
+                    no_version_header: true,
+                    ..Default::default()
+                },
+                &parsed
+            )
+            .unwrap(),
+            "export interface Bar {\n\tbaz: string;\n}\n\nexport interface Foo {\n\tbar: Bar;\n}\n\n"
+        );
+    }
+}
+
+mod external_types {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn undefined_referenced_types_are_reported() {
+        let source = r##"
+    #[typeshare]
+    pub struct Foo<T> {
+        pub bar: Bar,
+        pub generic: T,
+        pub url: Option<Url>,
+        pub times: HashMap<String, Vec<DateTime>>,
+    }
+
+    #[typeshare]
+    #[serde(tag = "type", content = "content")]
+    pub enum Bar {
+        Id(Uuid),
+        Wrapped { inner: Wrapper<Decimal> },
+    }
+
+    #[typeshare]
+    pub type Alias = Email;
+    "##;
+
+        let parsed = parse(source).unwrap();
+        let type_mappings: HashMap<String, String> = [("DateTime".to_string(), "Date".to_string())]
+            .into_iter()
+            .collect();
+
+        assert_eq!(
+            parsed
+                .external_types(&type_mappings)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec!["Decimal", "Email", "Url", "Uuid", "Wrapper"]
+        );
+    }
+}
@@ -54,10 +54,11 @@ fn check(
     file_name: impl AsRef<Path>,
     mut lang: Box<dyn Language>,
 ) -> Result<(), anyhow::Error> {
-    let _extension = file_name
+    let extension = file_name
         .as_ref()
         .extension()
         .expect("file name must have an extension");
+    assert_eq!(extension.to_str(), Some(lang.file_extension()));
     let expected_file_path = TESTS_FOLDER_PATH.join(test_name).join(&file_name);
 
     let rust_input = load_file(
@@ -66,11 +67,8 @@ fn check(
             .with_extension("rs"),
     )?;
 
-    let mut typeshare_output: Vec<u8> = Vec::new();
     let parsed_data = typeshare_core::parser::parse(&rust_input)?;
-    lang.generate_types(&mut typeshare_output, &parsed_data)?;
-
-    let typeshare_output = String::from_utf8(typeshare_output)?;
+    let typeshare_output = typeshare_core::generate_string(lang.as_mut(), &parsed_data)?;
     let expected = expect_test::expect_file![&expected_file_path];
     // Ensure that the unformatted typescript output matches what we expect
     expected.assert_eq(&typeshare_output);
@@ -96,6 +94,21 @@ macro_rules! output_file_for_ident {
     (go) => {
         "output.go"
     };
+    (json_schema) => {
+        "output.json"
+    };
+    (zod) => {
+        "output.zod.ts"
+    };
+    (graphql) => {
+        "output.graphql"
+    };
+    (csharp) => {
+        "output.cs"
+    };
+    (protobuf) => {
+        "output.proto"
+    };
 }
 
 /// Simplifies the construction of `Language` instances for each language.
@@ -213,6 +226,81 @@ macro_rules! language_instance {
             ..Default::default()
         })
     };
+
+    // Default JSON Schema
+    (json_schema) => {
+        language_instance!(json_schema { })
+    };
+
+    // JSON Schema with configuration fields forwarded
+    (json_schema {$($field:ident: $val:expr),* $(,)?}) => {
+        #[allow(clippy::needless_update)]
+        Box::new(typeshare_core::language::JsonSchema {
+            no_version_header: true,
+            $($field: $val,)*
+            ..Default::default()
+        })
+    };
+
+    // Default Zod
+    (zod) => {
+        language_instance!(zod { })
+    };
+
+    // Zod with configuration fields forwarded
+    (zod {$($field:ident: $val:expr),* $(,)?}) => {
+        #[allow(clippy::needless_update)]
+        Box::new(typeshare_core::language::Zod {
+            no_version_header: true,
+            $($field: $val,)*
+            ..Default::default()
+        })
+    };
+
+    // Default GraphQL
+    (graphql) => {
+        language_instance!(graphql { })
+    };
+
+    // GraphQL with configuration fields forwarded
+    (graphql {$($field:ident: $val:expr),* $(,)?}) => {
+        #[allow(clippy::needless_update)]
+        Box::new(typeshare_core::language::GraphQL {
+            no_version_header: true,
+            $($field: $val,)*
+            ..Default::default()
+        })
+    };
+
+    // Default C#
+    (csharp) => {
+        language_instance!(csharp { })
+    };
+
+    // C# with configuration fields forwarded
+    (csharp {$($field:ident: $val:expr),* $(,)?}) => {
+        #[allow(clippy::needless_update)]
+        Box::new(typeshare_core::language::CSharp {
+            no_version_header: true,
+            $($field: $val,)*
+            ..Default::default()
+        })
+    };
+
+    // Default Protobuf
+    (protobuf) => {
+        language_instance!(protobuf { })
+    };
+
+    // Protobuf with configuration fields forwarded
+    (protobuf {$($field:ident: $val:expr),* $(,)?}) => {
+        #[allow(clippy::needless_update)]
+        Box::new(typeshare_core::language::Protobuf {
+            no_version_header: true,
+            $($field: $val,)*
+            ..Default::default()
+        })
+    };
 }
 
 /// This macro removes the boilerplate involved in creating typeshare snapshot
@@ -391,6 +479,20 @@ tests! {
         typescript
     ];
     can_generate_slice_of_user_type: [swift, kotlin, scala, typescript, go];
+    can_generate_set_types: [swift, kotlin, scala, typescript, go, json_schema];
+    can_generate_json_schema: [json_schema];
+    can_generate_zod_schema: [zod];
+    can_generate_graphql_schema: [graphql];
+    can_generate_csharp_types: [
+        csharp {
+            namespace: "Company.Models".to_string(),
+        }
+    ];
+    can_generate_protobuf_messages: [
+        protobuf {
+            package: "company.models".to_string(),
+        }
+    ];
     can_generate_readonly_fields: [
         typescript
     ];
@@ -401,7 +503,8 @@ tests! {
         kotlin,
         scala,
         typescript,
-        go
+        go,
+        csharp
     ];
     can_generate_bare_string_enum: [swift, kotlin, scala, typescript, go ];
     can_generate_double_option_pattern: [
@@ -447,6 +550,7 @@ tests! {
 
     /// Structs
     can_generate_simple_struct_with_a_comment: [kotlin, swift, typescript, scala,  go];
+    can_generate_struct_with_a_block_comment: [kotlin, typescript];
     generate_types: [kotlin, swift, typescript, scala,  go];
     can_handle_serde_rename: [
         swift {
@@ -463,6 +567,7 @@ tests! {
     test_default_generic_constraints: [swift { default_generic_constraints: typeshare_core::language::GenericConstraints::from_config(vec!["Sendable".into(), "Identifiable".into()]) }];
     test_i54_u53_type: [swift, kotlin, scala,  typescript, go];
     test_serde_default_struct: [swift, kotlin, scala,  typescript, go];
+    test_serde_skip_serializing_if_struct: [swift, kotlin, scala,  typescript, go];
     test_serde_iso8601: [
         swift {
             prefix: String::new(),
@@ -553,6 +658,7 @@ tests! {
             uppercase_acronyms: vec!["ID".to_string(), "url".to_string()],
         },
     ];
+    can_resolve_transitive_struct_aliases: [go];
     resolves_qualified_type: [
         swift {
             prefix: "Core".into()